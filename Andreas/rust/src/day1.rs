@@ -1,23 +1,26 @@
 #![allow(dead_code)]
 
+use aoc_common::{parse_number, split_once, ParseError, Solution};
 use std::collections::HashMap;
-use std::fs;
 use std::iter::zip;
 
-fn parse_input(filename: &str) -> Vec<(i32, i32)> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+pub(crate) struct Day1;
+
+fn parse_input(file_contents: &str) -> Result<Vec<(i32, i32)>, ParseError> {
     file_contents
         .lines()
         .map(|line| {
-            let mut values = line.split("   ").map(|x| x.parse().unwrap());
-            (values.next().unwrap(), values.next().unwrap())
+            let (a, b) = split_once(file_contents, line, "   ")?;
+            Ok((
+                parse_number(file_contents, a)?,
+                parse_number(file_contents, b)?,
+            ))
         })
         .collect()
 }
 
-fn star1() {
-    let input = parse_input("day1_input.txt");
-    let lists: (Vec<_>, Vec<_>) = input.into_iter().unzip();
+fn star1(input: &[(i32, i32)]) -> i32 {
+    let lists: (Vec<_>, Vec<_>) = input.iter().copied().unzip();
     let list1_sorted = {
         let mut x = lists.0;
         x.sort();
@@ -28,13 +31,13 @@ fn star1() {
         x.sort();
         x
     };
-    let deltas: i32 = zip(list1_sorted.into_iter(), list2_sorted.into_iter()).map(|(x, y)| (x - y).abs()).sum();
-    println!("star 1: {}", deltas);
+    zip(list1_sorted, list2_sorted)
+        .map(|(x, y)| (x - y).abs())
+        .sum()
 }
 
-fn star2() {
-    let input = parse_input("day1_input.txt");
-    let lists: (Vec<_>, Vec<_>) = input.into_iter().unzip();
+fn star2(input: &[(i32, i32)]) -> i32 {
+    let lists: (Vec<_>, Vec<_>) = input.iter().copied().unzip();
     let list1_counter = {
         let mut counter = HashMap::new();
         for x in lists.0 {
@@ -46,10 +49,49 @@ fn star2() {
     for x in lists.1 {
         score += list1_counter.get(&x).unwrap_or(&0) * x;
     }
-    println!("{}", score);
+    score
+}
+
+impl Solution for Day1 {
+    type Input = Vec<(i32, i32)>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> i32 {
+        star1(input)
+    }
+
+    fn part2(input: &Self::Input) -> i32 {
+        star2(input)
+    }
 }
 
-pub(crate) fn main() {
-    star1();
-    star2();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part1(&input), 11);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::part2(&input), 31);
+    }
 }
@@ -1,8 +1,11 @@
 #![allow(dead_code)]
 
+use crate::grid::Grid;
+use aoc_common::{ParseError, Solution};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::fs;
+
+pub(crate) struct Day10;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 struct Pos(usize, usize);
@@ -27,7 +30,7 @@ enum Direction {
 }
 
 #[derive(Debug)]
-struct Input {
+pub(crate) struct Input {
     width: usize,
     height: usize,
     map: Vec<u8>,
@@ -43,19 +46,15 @@ impl Input {
     }
 }
 
-fn parse_input(filename: &str) -> Input {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    let width = file_contents.lines().next().unwrap().chars().count();
-    let height = file_contents.lines().count();
-    let heights = file_contents
-        .lines()
-        .flat_map(|line| line.chars().map(|c| c.to_digit(10).unwrap() as u8))
-        .collect();
-    Input {
-        width,
-        height,
-        map: heights,
-    }
+fn parse_input(file_contents: &str) -> Result<Input, ParseError> {
+    let grid = Grid::parse_from_string(file_contents, "a height digit", |c| {
+        c.to_digit(10).map(|h| h as u8)
+    })?;
+    Ok(Input {
+        width: grid.width as usize,
+        height: grid.height as usize,
+        map: grid.entities,
+    })
 }
 
 fn find_hikes(input: &Input) -> HashMap<Pos, HashSet<Vec<Pos>>> {
@@ -111,41 +110,77 @@ fn find_hikes(input: &Input) -> HashMap<Pos, HashSet<Vec<Pos>>> {
     result
 }
 
-fn star1(input: &Input, paths: &HashMap<Pos, HashSet<Vec<Pos>>>) {
+fn star1(input: &Input, paths: &HashMap<Pos, HashSet<Vec<Pos>>>) -> usize {
     let goals: HashMap<_, _> = paths
         .iter()
-        .map(|(&pos, foo)| {
+        .map(|(&pos, pos_paths)| {
             (
                 pos,
-                foo.iter().map(|paths| paths[0]).collect::<HashSet<_>>(),
+                pos_paths
+                    .iter()
+                    .map(|paths| paths[0])
+                    .collect::<HashSet<_>>(),
             )
         })
         .collect();
 
-    let zero_goals_sum: usize = goals
+    goals
         .iter()
         .filter(|(pos, _)| input.get(**pos).unwrap() == 0)
         .map(|(_, goals)| goals.len())
-        .sum();
-
-    println!("Star 1: {}", zero_goals_sum);
+        .sum()
 }
 
-fn star2(input: &Input, paths: &HashMap<Pos, HashSet<Vec<Pos>>>) {
-    let zero_paths_sum: usize = paths
+fn star2(input: &Input, paths: &HashMap<Pos, HashSet<Vec<Pos>>>) -> usize {
+    paths
         .iter()
         .filter(|(pos, _)| input.get(**pos).unwrap() == 0)
         .map(|(_, paths)| paths.len())
-        .sum();
-
-    println!("Star 2: {}", zero_paths_sum);
+        .sum()
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day10_input.txt");
+impl Solution for Day10 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        star1(input, &find_hikes(input))
+    }
 
-    let paths = find_hikes(&input);
+    fn part2(input: &Self::Input) -> usize {
+        star2(input, &find_hikes(input))
+    }
+}
 
-    star1(&input, &paths);
-    star2(&input, &paths);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), 81);
+    }
 }
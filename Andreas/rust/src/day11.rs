@@ -1,14 +1,15 @@
 #![allow(dead_code)]
 
-use std::fs;
+use aoc_common::{parse_number, ParseError, Solution};
+
+pub(crate) struct Day11;
 
 const MAX_STEPS: usize = 100;
 
-fn parse_input(filename: &str) -> Vec<u64> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+fn parse_input(file_contents: &str) -> Result<Vec<u64>, ParseError> {
     file_contents
         .split_whitespace()
-        .map(|x| x.parse().unwrap())
+        .map(|x| parse_number(file_contents, x))
         .collect()
 }
 
@@ -16,8 +17,8 @@ type PrecomputeArray = [[usize; MAX_STEPS + 1]; 10];
 
 fn precompute_base_stones() -> PrecomputeArray {
     let mut result: PrecomputeArray = [[usize::MAX; MAX_STEPS + 1]; 10];
-    for i in 0..=9 {
-        result[i][0] = 1;
+    for row in result.iter_mut() {
+        row[0] = 1;
     }
 
     fn compute(n: u64, steps: usize, result: &mut PrecomputeArray) -> usize {
@@ -25,7 +26,7 @@ fn precompute_base_stones() -> PrecomputeArray {
             1
         } else if n >= 10 {
             let n_digits = n.ilog10() + 1;
-            if n_digits % 2 == 0 {
+            if n_digits.is_multiple_of(2) {
                 let digits_pow = 10u64.pow(n_digits / 2);
                 let n_lhs = n / digits_pow;
                 let n_rhs = n % digits_pow;
@@ -58,7 +59,7 @@ fn count_stones(n: u64, steps: usize, precomputed_base: &PrecomputeArray) -> usi
         precomputed_base[n as usize][steps]
     } else {
         let n_digits = n.ilog10() + 1;
-        if n_digits % 2 == 0 {
+        if n_digits.is_multiple_of(2) {
             let digits_pow = 10u64.pow(n_digits / 2);
             let n_lhs = n / digits_pow;
             let n_rhs = n % digits_pow;
@@ -70,23 +71,49 @@ fn count_stones(n: u64, steps: usize, precomputed_base: &PrecomputeArray) -> usi
     }
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day11_input.txt");
-    let base_stones = precompute_base_stones();
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
 
-    println!(
-        "Star 1: {}",
+    fn part1(input: &Self::Input) -> usize {
+        let base_stones = precompute_base_stones();
         input
             .iter()
             .map(|x| count_stones(*x, 25, &base_stones))
-            .sum::<usize>()
-    );
+            .sum()
+    }
 
-    println!(
-        "Star 2: {}",
+    fn part2(input: &Self::Input) -> usize {
+        let base_stones = precompute_base_stones();
         input
             .iter()
             .map(|x| count_stones(*x, 75, &base_stones))
-            .sum::<usize>()
-    );
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+125 17
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), 55312);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input), 65601038650482);
+    }
 }
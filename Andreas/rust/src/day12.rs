@@ -1,172 +1,122 @@
 #![allow(dead_code)]
 
-use std::collections::HashSet;
-use std::fs;
-
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-struct Pos(usize, usize);
-
-impl Pos {
-    fn neighbours(&self) -> [Pos; 4] {
-        [
-            self.mov(Direction::Down),
-            self.mov(Direction::Up),
-            self.mov(Direction::Left),
-            self.mov(Direction::Right),
-        ]
-    }
+use crate::grid::Grid;
+use aoc_common::render::{Cell, Frame, Rgb};
+use aoc_common::{ParseError, Solution};
 
-    fn mov(&self, direction: Direction) -> Pos {
-        match direction {
-            Direction::Up => Pos(self.0.wrapping_sub(1), self.1),
-            Direction::Down => Pos(self.0.wrapping_add(1), self.1),
-            Direction::Left => Pos(self.0, self.1.wrapping_sub(1)),
-            Direction::Right => Pos(self.0, self.1.wrapping_add(1)),
-        }
-    }
-}
+pub(crate) struct Day12;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+fn parse_input(file_contents: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_from_string(file_contents, "a plant letter", |c| {
+        c.is_ascii_uppercase().then_some(c)
+    })
 }
 
-static ALL_DIRECTIONS: [Direction; 4] = [
-    Direction::Up,
-    Direction::Down,
-    Direction::Left,
-    Direction::Right,
-];
-
-impl Direction {
-    fn all() -> &'static [Direction; 4] {
-        &ALL_DIRECTIONS
-    }
+/// Every region in its own colour, so that regions of the same plant can be told apart.
+fn render_regions(garden_plot: &Grid<char>) -> Frame {
+    let regions = garden_plot.regions();
+    garden_plot.render(|pos, &plant| {
+        let region = regions.label(pos.into()).unwrap();
+        Cell::coloured(plant, Rgb::palette(region))
+    })
 }
 
-#[derive(Debug)]
-struct GardenPlot {
-    width: usize,
-    height: usize,
-    plant_map: Vec<char>,
+pub(crate) fn render(file_contents: &str) -> Result<Frame, ParseError> {
+    Ok(render_regions(&parse_input(file_contents)?))
 }
 
-impl GardenPlot {
-    fn get(&self, pos: Pos) -> Option<char> {
-        if pos.0 >= self.width || pos.1 >= self.height {
-            None
-        } else {
-            Some(self.plant_map[pos.0 + pos.1 * self.width])
-        }
-    }
-}
+impl Solution for Day12 {
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-fn parse_input(filename: &str) -> GardenPlot {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    let width = file_contents.lines().next().unwrap().chars().count();
-    let height = file_contents.lines().count();
-    let plant_map = file_contents
-        .lines()
-        .flat_map(|line| line.chars())
-        .collect();
-    GardenPlot {
-        width,
-        height,
-        plant_map,
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
-}
 
-fn find_regions(garden_plot: &GardenPlot) -> Vec<HashSet<Pos>> {
-    let mut regions: Vec<HashSet<Pos>> = Vec::new();
-
-    fn dfs_region(garden_plot: &GardenPlot, pos: Pos, region: &mut HashSet<Pos>) {
-        if region.contains(&pos) {
-            return;
-        }
-        region.insert(pos);
-        let c = garden_plot.get(pos).unwrap();
-        for neighbour in pos.neighbours() {
-            if garden_plot.get(neighbour) == Some(c) {
-                dfs_region(garden_plot, neighbour, region);
-            }
-        }
+    fn part1(input: &Self::Input) -> usize {
+        input
+            .regions()
+            .iter()
+            .map(|region| region.area() * region.perimeter())
+            .sum()
     }
 
-    for x in 0..garden_plot.width {
-        for y in 0..garden_plot.height {
-            let pos = Pos(x, y);
-            if regions.iter_mut().find(|r| r.contains(&pos)).is_none() {
-                regions.push(HashSet::new());
-                dfs_region(garden_plot, pos, regions.last_mut().unwrap());
-            };
-        }
+    fn part2(input: &Self::Input) -> usize {
+        input
+            .regions()
+            .iter()
+            .map(|region| region.area() * region.sides())
+            .sum()
     }
-
-    regions
-}
-
-fn region_perimeter(region: &HashSet<Pos>) -> HashSet<(Pos, Direction)> {
-    region
-        .iter()
-        .flat_map(|&pos| {
-            Direction::all().iter().filter_map(move |&direction| {
-                if region.contains(&pos.mov(direction)) {
-                    None
-                } else {
-                    Some((pos, direction))
-                }
-            })
-        })
-        .collect()
 }
 
-fn region_sides(region: &HashSet<Pos>) -> HashSet<(Pos, Direction)> {
-    let perimeter = region_perimeter(region);
-    let mut sides = HashSet::new();
-
-    for &(pos, fence_direction) in perimeter.iter() {
-        let orthogonal_direction =
-            if fence_direction == Direction::Up || fence_direction == Direction::Down {
-                Direction::Left
-            } else {
-                Direction::Up
-            };
-        let mut sliding_pos = pos;
-        loop {
-            let next_sliding_pos = sliding_pos.mov(orthogonal_direction);
-            if !region.contains(&next_sliding_pos)
-                || !perimeter.contains(&(next_sliding_pos, fence_direction))
-            {
-                break;
-            }
-            sliding_pos = next_sliding_pos;
-        }
-        sides.insert((sliding_pos, fence_direction));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const E_SHAPED_EXAMPLE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const NESTED_EXAMPLE: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), 1930);
     }
 
-    sides
-}
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 1206);
+    }
 
-pub(crate) fn main() {
-    let garden_plot = parse_input("day12_input.txt");
-    let regions = find_regions(&garden_plot);
+    #[test]
+    fn part2_e_shaped_example() {
+        let input = Day12::parse(E_SHAPED_EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 236);
+    }
 
-    println!(
-        "Star 1: {}",
-        regions
-            .iter()
-            .map(|region| region.len() * region_perimeter(region).len())
-            .sum::<usize>()
-    );
+    #[test]
+    fn part2_nested_example() {
+        let input = Day12::parse(NESTED_EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 368);
+    }
 
-    println!(
-        "Star 2: {}",
-        regions
-            .iter()
-            .map(|region| region.len() * region_sides(region).len())
-            .sum::<usize>()
-    );
+    #[test]
+    fn regions_of_the_same_plant_get_different_colours() {
+        let input = Day12::parse(E_SHAPED_EXAMPLE).unwrap();
+        let frame = render_regions(&input);
+        assert_eq!(frame.to_string(), E_SHAPED_EXAMPLE);
+        let colour = |x, y| frame.cell(x, y).unwrap().colour;
+        assert_eq!(colour(1, 1), colour(4, 1));
+        assert_ne!(colour(1, 1), colour(1, 3));
+        assert_ne!(colour(0, 0), colour(1, 1));
+    }
 }
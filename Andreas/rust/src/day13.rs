@@ -1,35 +1,40 @@
 #![allow(dead_code)]
 
-use itertools::Itertools;
-use regex::{Captures, Regex};
-use std::fs;
+use aoc_common::{parse_number, ParseError, Solution};
+use regex::Regex;
 
-#[derive(Debug)]
-struct ClawMachine {
+pub(crate) struct Day13;
+
+#[derive(Debug, Clone)]
+pub(crate) struct ClawMachine {
     a_delta: (i64, i64),
     b_delta: (i64, i64),
     prize: (i64, i64),
 }
 
-fn parse_input(filename: &str) -> Vec<ClawMachine> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+fn parse_input(file_contents: &str) -> Result<Vec<ClawMachine>, ParseError> {
     let machine_blocks = file_contents.split("\n\n");
-    let button_regex = Regex::new(r"Button \w: X\+(\d+), Y\+(\d+)").unwrap();
-    let prize_regex = Regex::new(r"Prize: X=(\d+), Y=(\d+)").unwrap();
-    let unwrap_capture = |captures: Captures| -> (i64, i64) {
-        (
-            captures.get(1).unwrap().as_str().parse().unwrap(),
-            captures.get(2).unwrap().as_str().parse().unwrap(),
-        )
-    };
+    let button_regex = Regex::new(r"^Button \w: X\+(\d+), Y\+(\d+)$").unwrap();
+    let prize_regex = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap();
+    let parse_line =
+        |line: &str, regex: &Regex, expected: &str| -> Result<(i64, i64), ParseError> {
+            let captures = regex
+                .captures(line)
+                .ok_or_else(|| ParseError::at(file_contents, line, expected))?;
+            Ok((
+                parse_number(file_contents, captures.get(1).unwrap().as_str())?,
+                parse_number(file_contents, captures.get(2).unwrap().as_str())?,
+            ))
+        };
     machine_blocks
         .map(|block| {
-            let (a_line, b_line, prize_line) = block.lines().collect_tuple().unwrap();
-            ClawMachine {
-                a_delta: { unwrap_capture(button_regex.captures(a_line).expect(a_line)) },
-                b_delta: { unwrap_capture(button_regex.captures(b_line).expect(b_line)) },
-                prize: { unwrap_capture(prize_regex.captures(prize_line).expect(prize_line)) },
-            }
+            let mut lines = block.lines();
+            let mut next_line = || lines.next().unwrap_or(&block[block.len()..]);
+            Ok(ClawMachine {
+                a_delta: parse_line(next_line(), &button_regex, "\"Button A: X+.., Y+..\"")?,
+                b_delta: parse_line(next_line(), &button_regex, "\"Button B: X+.., Y+..\"")?,
+                prize: parse_line(next_line(), &prize_regex, "\"Prize: X=.., Y=..\"")?,
+            })
         })
         .collect()
 }
@@ -50,19 +55,67 @@ fn get_button_presses(machine: &ClawMachine) -> Option<(i64, i64)> {
 fn compute_cost_sum(machines: &[ClawMachine]) -> u64 {
     let costs: Vec<_> = machines
         .iter()
-        .filter_map(|m| get_button_presses(&m))
+        .filter_map(get_button_presses)
         .map(|(a_press, b_press)| 3 * a_press + b_press)
         .collect();
     //println!("{:?}", costs);
     costs.iter().map(|c| *c as u64).sum()
 }
 
-pub(crate) fn main() {
-    let mut machines = parse_input("day13_input.txt");
-    println!("Star 1: {}", compute_cost_sum(&machines));
-    for machine in machines.iter_mut() {
-        machine.prize.0 += 10000000000000;
-        machine.prize.1 += 10000000000000;
+impl Solution for Day13 {
+    type Input = Vec<ClawMachine>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        compute_cost_sum(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        let mut machines = input.clone();
+        for machine in machines.iter_mut() {
+            machine.prize.0 += 10000000000000;
+            machine.prize.1 += 10000000000000;
+        }
+        compute_cost_sum(&machines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), 480);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input), 875318608908);
     }
-    println!("Star 2: {}", compute_cost_sum(&machines));
 }
@@ -1,86 +1,95 @@
 #![allow(dead_code)]
 
+use crate::grid::{GridRead, Offset, Point, Position, Torus};
+use crate::playback::{Animated, Simulation};
+use crate::sparse_grid::SparseGrid;
+use aoc_common::render::{Cell, Frame, Rgb};
+use aoc_common::{parse_number, MaybeAnswer, ParseError, Solution};
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::any::Any;
 
-const WIDTH: u32 = 101;
-const HEIGHT: u32 = 103;
+pub(crate) struct Day14;
 
-#[derive(Debug)]
-struct Robot {
-    start: (u32, u32),
-    velocity: (i32, i32),
+#[derive(Debug, Clone)]
+pub(crate) struct Robot {
+    start: Position,
+    velocity: Offset,
 }
 
-fn parse_input(filename: &str) -> Vec<Robot> {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    let regex = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
+/// The room of the puzzle, it wraps around its edges. The example takes place in a smaller room
+/// that the input does not tell about, see [`resize`].
+const ROOM: Torus = Torus::new(101, 103);
+
+/// The robots and the room they move in.
+#[derive(Debug, Clone)]
+pub(crate) struct Robots {
+    robots: Vec<Robot>,
+    room: Torus,
+}
+
+/// Moves the parsed robots into a `width` x `height` room.
+pub(crate) fn resize(input: &mut dyn Any, (width, height): (u32, u32)) {
+    input
+        .downcast_mut::<Robots>()
+        .expect("input was parsed by day 14")
+        .room = Torus::new(width, height);
+}
+
+fn parse_input(file_contents: &str) -> Result<Vec<Robot>, ParseError> {
+    let regex = Regex::new(r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$").unwrap();
     file_contents
         .lines()
         .map(|line| {
-            let captures = regex.captures(line).unwrap();
-            Robot {
-                start: (
-                    captures.get(1).unwrap().as_str().parse().unwrap(),
-                    captures.get(2).unwrap().as_str().parse().unwrap(),
+            let captures = regex
+                .captures(line)
+                .ok_or_else(|| ParseError::at(file_contents, line, "\"p=x,y v=dx,dy\""))?;
+            let capture = |i| captures.get(i).unwrap().as_str();
+            Ok(Robot {
+                start: Position::new(
+                    parse_number(file_contents, capture(1))?,
+                    parse_number(file_contents, capture(2))?,
                 ),
-                velocity: (
-                    captures.get(3).unwrap().as_str().parse().unwrap(),
-                    captures.get(4).unwrap().as_str().parse().unwrap(),
+                velocity: Offset::new(
+                    parse_number(file_contents, capture(3))?,
+                    parse_number(file_contents, capture(4))?,
                 ),
-            }
+            })
         })
         .collect()
 }
 
-fn pretty_print(positions: &[(u32, u32)]) -> String {
-    let mut result = String::new();
-    let positions_map = {
-        let mut m = HashMap::new();
-        for c in positions {
-            *m.entry(c).or_insert(0) += 1;
-        }
-        m
-    };
-    for y in 0..HEIGHT {
-        for x in 0..WIDTH {
-            let count = *positions_map.get(&(x, y)).unwrap_or(&0);
-            let c = if count == 0 {
-                '.'
-            } else {
-                char::from_digit(count, 36).unwrap()
-            };
-            result.push(c);
-        }
-        result += "\n";
+/// Robots per tile as a base-36 digit, tiles with robots are highlighted.
+fn render_robots(positions: &[Position], room: Torus) -> Frame {
+    let mut counts = SparseGrid::toroidal(room);
+    for &position in positions {
+        let position = position.into();
+        let count = counts.get(position).copied().unwrap_or(0);
+        counts.insert(position, count + 1);
     }
-    result
+    counts.render_extent(|_, count| match count {
+        Some(&count) => Cell::coloured(char::from_digit(count.min(35), 36).unwrap(), Rgb::GREEN),
+        None => Cell::new('.'),
+    })
 }
 
-fn simulate_seconds(robots: &[Robot], seconds: u32) -> Vec<(u32, u32)> {
+fn simulate_seconds(robots: &[Robot], seconds: u32, room: Torus) -> Vec<Position> {
     robots
         .iter()
-        .map(|robot| {
-            (
-                (robot.start.0 + robot.velocity.0 as u32 * seconds) % WIDTH,
-                (robot.start.1 + robot.velocity.1 as u32 * seconds) % HEIGHT,
-            )
-        })
+        .map(|robot| room.offset(robot.start, robot.velocity * seconds as i32))
         .collect()
 }
 
-fn count_quadrants(positions: &[(u32, u32)]) -> [u32; 4] {
+fn count_quadrants(positions: &[Position], room: Torus) -> [u32; 4] {
     let mut quadrants = [0; 4];
-    for &(x, y) in positions {
+    for &Position { x, y } in positions {
         let mut i = 0;
-        if x == WIDTH / 2 || y == HEIGHT / 2 {
+        if x == room.width / 2 || y == room.height / 2 {
             continue;
         }
-        if x > WIDTH / 2 {
+        if x > room.width / 2 {
             i += 1;
         }
-        if y > HEIGHT / 2 {
+        if y > room.height / 2 {
             i += 2;
         }
         quadrants[i] += 1;
@@ -88,57 +97,149 @@ fn count_quadrants(positions: &[(u32, u32)]) -> [u32; 4] {
     quadrants
 }
 
-fn search_for_christmas_egg(robots: &[Robot]) {
-    fn connectivity(positions: &[(u32, u32)]) -> u32 {
-        let positions_set: HashSet<_> = positions.iter().collect();
+fn safety_factor(robots: &[Robot], room: Torus) -> u32 {
+    count_quadrants(&simulate_seconds(robots, 100, room), room)
+        .iter()
+        .product()
+}
+
+fn search_for_christmas_egg(robots: &[Robot], room: Torus) -> Option<u32> {
+    fn connectivity(positions: &[Position], room: Torus) -> u32 {
+        // Bounded instead of toroidal, robots on opposite edges are not next to each other.
+        let mut robots = SparseGrid::bounded(room.width, room.height);
+        for &position in positions {
+            robots.insert(position.into(), ());
+        }
         let mut result = 0;
-        for x in 0..WIDTH {
-            for y in 0..HEIGHT {
-                let neighbours = [
-                    (x + 1, y),
-                    (x.wrapping_sub(1), y),
-                    (x, y + 1),
-                    (x, y.wrapping_sub(1)),
-                ];
-                result += neighbours
-                    .iter()
-                    .filter(|p| positions_set.contains(p))
-                    .count()
-                    .pow(2);
+        for x in 0..room.width as i32 {
+            for y in 0..room.height as i32 {
+                result += robots.neighbours4(Point::new(x, y)).count().pow(2);
             }
         }
         result as u32
     }
 
-    for i in 0..10000 {
-        let pos = simulate_seconds(robots, i);
-        let c = connectivity(&pos);
-        if c > 2500 {
-            println!("{} {}", i, c);
+    (0..10000).find(|&i| connectivity(&simulate_seconds(robots, i, room), room) > 2500)
+}
+
+/// The robots second by second, until their positions repeat.
+#[derive(Debug, Clone)]
+pub(crate) struct RobotMotion {
+    robots: Vec<Robot>,
+    room: Torus,
+    seconds: u32,
+}
+
+impl Simulation for RobotMotion {
+    fn step(&mut self) -> bool {
+        // Every robot is back at its start after width * height seconds.
+        if self.seconds + 1 >= self.room.width * self.room.height {
+            return false;
         }
+        self.seconds += 1;
+        true
+    }
+
+    fn render(&self) -> Frame {
+        render_robots(
+            &simulate_seconds(&self.robots, self.seconds, self.room),
+            self.room,
+        )
+    }
+
+    fn status(&self) -> String {
+        format!("{} seconds", self.seconds)
     }
 }
 
-pub(crate) fn main() {
-    let mut input = parse_input("day14_input.txt");
-    for robot in input.iter_mut() {
-        while robot.velocity.0 < 0 {
-            robot.velocity.0 += WIDTH as i32;
-        }
-        while robot.velocity.1 < 0 {
-            robot.velocity.1 += HEIGHT as i32;
+impl Solution for Day14 {
+    type Input = Robots;
+    type Answer1 = u32;
+    type Answer2 = MaybeAnswer<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Robots {
+            robots: parse_input(input)?,
+            room: ROOM,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        safety_factor(&input.robots, input.room)
+    }
+
+    fn part2(input: &Self::Input) -> MaybeAnswer<u32> {
+        search_for_christmas_egg(&input.robots, input.room).into()
+    }
+}
+
+impl Animated for Day14 {
+    type Simulation = RobotMotion;
+
+    fn simulation(input: &Self::Input, _part: u8) -> RobotMotion {
+        RobotMotion {
+            robots: input.robots.clone(),
+            room: input.room,
+            seconds: 0,
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    fn example() -> Robots {
+        let mut input = Day14::parse(EXAMPLE).unwrap();
+        resize(&mut input, (11, 7));
+        input
+    }
 
-    let positions_after_100_sec = simulate_seconds(&input, 100);
-    println!(
-        "Star 1: {}",
-        count_quadrants(&positions_after_100_sec)
-            .iter()
-            .product::<u32>()
-    );
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day14::part1(&example()), 12);
+    }
+
+    #[test]
+    fn part2_example_has_no_christmas_tree() {
+        assert_eq!(Day14::part2(&example()).to_string(), "no answer");
+    }
+
+    #[test]
+    fn parse_assumes_the_room_of_the_puzzle() {
+        assert_eq!(Day14::parse(EXAMPLE).unwrap().room, Torus::new(101, 103));
+    }
 
-    for i in [8179] {
-        println!("{}", pretty_print(&simulate_seconds(&input, i)));
+    #[test]
+    fn render_example_after_100_seconds() {
+        let Robots { robots, room } = example();
+        let frame = render_robots(&simulate_seconds(&robots, 100, room), room);
+        assert_eq!(
+            frame.to_string(),
+            "\
+......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....
+"
+        );
     }
 }
@@ -1,9 +1,13 @@
 #![allow(dead_code)]
 
 use crate::grid::{Direction, Grid, Position};
+use crate::playback::{Animated, Simulation};
+use aoc_common::render::{Cell, Frame, Rgb};
+use aoc_common::{parse_chars, split_once, ParseError, Solution};
 use itertools::Itertools;
 use std::cmp::PartialEq;
-use std::fs;
+
+pub(crate) struct Day15;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 enum GridTile {
@@ -16,49 +20,54 @@ enum GridTile {
 }
 
 #[derive(Debug)]
-struct Input {
+pub(crate) struct Input {
     start_grid: Grid<GridTile>,
     move_plan: Vec<Direction>,
 }
 
-fn parse_input(filename: &str) -> Input {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    let (grid_input, plan_input) = file_contents.split("\n\n").collect_tuple().unwrap();
-
-    let start_grid = Grid {
-        width: grid_input.lines().next().unwrap().chars().count() as u32,
-        height: grid_input.lines().count() as u32,
-        entities: grid_input
-            .chars()
-            .filter(|c| !c.is_whitespace())
-            .map(|c| match c {
-                '.' => GridTile::Empty,
-                'O' => GridTile::Box,
-                '[' => GridTile::BigBoxLeft,
-                ']' => GridTile::BigBoxRight,
-                '#' => GridTile::Wall,
-                '@' => GridTile::Robot,
-                _ => unreachable!(),
-            })
-            .collect(),
-    };
-
-    let move_plan = plan_input
-        .chars()
-        .filter(|c| !c.is_whitespace())
-        .map(|c| match c {
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            _ => unreachable!(),
-        })
-        .collect();
+fn parse_input(file_contents: &str) -> Result<Input, ParseError> {
+    let (grid_input, plan_input) = split_once(file_contents, file_contents, "\n\n")?;
+
+    let start_grid = Grid::parse_from_string(grid_input, "a warehouse tile", |c| match c {
+        '.' => Some(GridTile::Empty),
+        'O' => Some(GridTile::Box),
+        '#' => Some(GridTile::Wall),
+        '@' => Some(GridTile::Robot),
+        _ => None,
+    })?;
+    // Errors point at the second robot, or behind the grid if there is none.
+    let mut robots = grid_input
+        .lines()
+        .flat_map(|line| line.match_indices('@').map(move |(x, _)| &line[x..x + 1]));
+    match (robots.next(), robots.next()) {
+        (None, _) => {
+            let end = &grid_input[grid_input.len()..];
+            return Err(ParseError::at(file_contents, end, "a robot '@'"));
+        }
+        (Some(_), Some(second)) => {
+            return Err(ParseError::at(
+                file_contents,
+                second,
+                "exactly one robot '@'",
+            ));
+        }
+        (Some(_), None) => {}
+    }
 
-    Input {
+    let mut move_plan = Vec::new();
+    for line in plan_input.lines() {
+        move_plan.extend(parse_chars(
+            file_contents,
+            line,
+            "'<', '>', '^' or 'v'",
+            |c| Direction::try_from(c).ok(),
+        )?);
+    }
+
+    Ok(Input {
         start_grid,
         move_plan,
-    }
+    })
 }
 
 fn pp_tile(_position: Position, grid_tile: &GridTile) -> char {
@@ -83,8 +92,9 @@ fn apply_move(grid: &mut Grid<GridTile>, direction: Direction) {
         let result = match entity {
             GridTile::Empty => Some(Vec::new()),
             GridTile::Box | GridTile::BigBoxLeft | GridTile::BigBoxRight | GridTile::Robot => {
-                let mut rec =
-                    affected_by_moving_entity(grid, direction, position.move_to(direction), true);
+                // Leaving the grid is blocked like walking into a wall.
+                let next = grid.try_move(position, direction)?;
+                let mut rec = affected_by_moving_entity(grid, direction, next, true);
                 if let Some(v) = &mut rec {
                     v.push(position);
                 }
@@ -92,30 +102,31 @@ fn apply_move(grid: &mut Grid<GridTile>, direction: Direction) {
             }
             GridTile::Wall => None,
         };
-        if result.is_some()
-            && step_sideways
-            && (direction == Direction::Up || direction == Direction::Down)
-            && (entity == GridTile::BigBoxLeft || entity == GridTile::BigBoxRight)
-        {
-            let neighbour_pos = if entity == GridTile::BigBoxLeft {
-                position.move_to(Direction::Right)
-            } else {
-                position.move_to(Direction::Left)
-            };
-            let neighbour_move = affected_by_moving_entity(grid, direction, neighbour_pos, false);
-            if neighbour_move.is_none() {
-                None
-            } else {
-                let mut x = result.unwrap();
-                x.extend(neighbour_move.unwrap());
+        match result {
+            Some(mut x)
+                if step_sideways
+                    && (direction == Direction::Up || direction == Direction::Down)
+                    && (entity == GridTile::BigBoxLeft || entity == GridTile::BigBoxRight) =>
+            {
+                let neighbour_pos = if entity == GridTile::BigBoxLeft {
+                    grid.try_move(position, Direction::Right)?
+                } else {
+                    grid.try_move(position, Direction::Left)?
+                };
+                let neighbour_move =
+                    affected_by_moving_entity(grid, direction, neighbour_pos, false)?;
+                x.extend(neighbour_move);
                 Some(x)
             }
-        } else {
-            result
+            result => result,
         }
     }
 
-    let robot_pos = grid.find(&GridTile::Robot).exactly_one().ok().unwrap();
+    let robot_pos = grid
+        .find(&GridTile::Robot)
+        .exactly_one()
+        .ok()
+        .expect("the parser checks for exactly one robot");
     let affected_positions = affected_by_moving_entity(grid, direction, robot_pos, true);
     if let Some(affected_positions) = affected_positions {
         let old_grid = grid.clone(); // slow as hell
@@ -159,28 +170,201 @@ fn widen_grid(grid: Grid<GridTile>) -> Grid<GridTile> {
     }
 }
 
-fn star1(input: &Input) {
-    let mut grid = input.start_grid.clone();
-    println!("{}", grid.pretty_print(&pp_tile));
-    for &direction in input.move_plan.iter() {
+fn run_move_plan(mut grid: Grid<GridTile>, move_plan: &[Direction]) -> Grid<GridTile> {
+    for &direction in move_plan.iter() {
         apply_move(&mut grid, direction);
     }
-    println!("{}", grid.pretty_print(&pp_tile));
-    println!("Star 1: {}", gps_coordinate_sum(&grid));
+    grid
 }
 
-fn star2(input: &Input) {
-    let mut grid = widen_grid(input.start_grid.clone());
-    println!("{}", grid.pretty_print(&pp_tile));
-    for &direction in input.move_plan.iter() {
-        apply_move(&mut grid, direction);
+/// The robot working through its move plan.
+#[derive(Debug, Clone)]
+pub(crate) struct Warehouse {
+    grid: Grid<GridTile>,
+    move_plan: Vec<Direction>,
+    moves_done: usize,
+}
+
+impl Simulation for Warehouse {
+    fn step(&mut self) -> bool {
+        let Some(&direction) = self.move_plan.get(self.moves_done) else {
+            return false;
+        };
+        apply_move(&mut self.grid, direction);
+        self.moves_done += 1;
+        true
+    }
+
+    fn render(&self) -> Frame {
+        self.grid.render(|position, tile| {
+            let glyph = pp_tile(position, tile);
+            match tile {
+                GridTile::Empty => Cell::new(glyph),
+                GridTile::Wall => Cell::coloured(glyph, Rgb::GREY),
+                GridTile::Robot => Cell::coloured(glyph, Rgb::RED),
+                GridTile::Box | GridTile::BigBoxLeft | GridTile::BigBoxRight => {
+                    Cell::coloured(glyph, Rgb::YELLOW)
+                }
+            }
+        })
+    }
+
+    fn status(&self) -> String {
+        let next = match self.move_plan.get(self.moves_done) {
+            Some(&direction) => format!("next move {}", char::from(direction)),
+            None => "all moves done".to_string(),
+        };
+        format!(
+            "{next} ({}/{}), GPS sum {}",
+            self.moves_done,
+            self.move_plan.len(),
+            gps_coordinate_sum(&self.grid)
+        )
     }
-    println!("{}", grid.pretty_print(&pp_tile));
-    println!("Star 2: {}", gps_coordinate_sum(&grid));
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day15_input.txt");
-    star1(&input);
-    star2(&input);
+impl Solution for Day15 {
+    type Input = Input;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        let grid = run_move_plan(input.start_grid.clone(), &input.move_plan);
+        gps_coordinate_sum(&grid)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        let grid = run_move_plan(widen_grid(input.start_grid.clone()), &input.move_plan);
+        gps_coordinate_sum(&grid)
+    }
+}
+
+impl Animated for Day15 {
+    type Simulation = Warehouse;
+
+    /// Part 2 plays the move plan in the widened warehouse.
+    fn simulation(input: &Self::Input, part: u8) -> Warehouse {
+        let grid = match part {
+            1 => input.start_grid.clone(),
+            _ => widen_grid(input.start_grid.clone()),
+        };
+        Warehouse {
+            grid,
+            move_plan: input.move_plan.clone(),
+            moves_done: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const LARGE_EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    const WIDE_EXAMPLE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day15::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), 2028);
+    }
+
+    #[test]
+    fn part1_large_example() {
+        let input = Day15::parse(LARGE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), 10092);
+    }
+
+    #[test]
+    fn part2_large_example() {
+        let input = Day15::parse(LARGE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), 9021);
+    }
+
+    #[test]
+    fn part2_wide_example() {
+        let input = Day15::parse(WIDE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), 618);
+    }
+
+    #[test]
+    fn playback_runs_move_plan() {
+        let input = Day15::parse(SMALL_EXAMPLE).unwrap();
+        let mut warehouse = Day15::simulation(&input, 1);
+        while warehouse.step() {}
+        assert_eq!(gps_coordinate_sum(&warehouse.grid), 2028);
+        assert!(warehouse.status().starts_with("all moves done (15/15)"));
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_robots() {
+        let err = Day15::parse("#@.#\n#.@#\n\n<\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:3: expected exactly one robot '@', found \"@\""
+        );
+        let err = Day15::parse("#..#\n#O.#\n\n<\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:5: expected a robot '@', found end of line"
+        );
+        assert!(Day15::parse("#@[]#\n\n<\n").is_err());
+    }
+
+    #[test]
+    fn leaving_a_warehouse_without_walls_is_blocked() {
+        let input = Day15::parse("..O@\n....\n\n<v<<<<^^\n").unwrap();
+        assert_eq!(Day15::part1(&input), 1);
+        assert_eq!(Day15::part2(&input), 3);
+    }
 }
@@ -1,14 +1,18 @@
 #![allow(dead_code)]
 
 use crate::grid::{Direction, Grid, Position};
+use aoc_common::path::{dijkstra, ShortestPaths};
+use aoc_common::render::{Cell, Frame, Rgb};
+use aoc_common::{MaybeAnswer, ParseError, Solution};
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::collections::HashSet;
+
+pub(crate) struct Day16;
 
 type PathNode = (Position, Direction);
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-enum Tile {
+pub(crate) enum Tile {
     Empty,
     Wall,
     Start,
@@ -26,125 +30,215 @@ impl From<Tile> for char {
     }
 }
 
-fn parse_input(filename: &str) -> Grid<Tile> {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    Grid::parse_from_string(&file_contents, |c| match c {
-        '.' => Tile::Empty,
-        '#' => Tile::Wall,
-        'S' => Tile::Start,
-        'E' => Tile::End,
-        _ => unreachable!(),
-    })
-        .unwrap()
-}
-
-fn find_shortest_path(grid: &Grid<Tile>) -> (HashMap<PathNode, Vec<PathNode>>, u32) {
-    let start = grid.find(&Tile::Start).exactly_one().ok().unwrap();
-    let end = grid.find(&Tile::End).exactly_one().ok().unwrap();
-
-    let mut costs = HashMap::new();
-    let mut prevs = HashMap::new();
-    let mut nodes_to_visit = vec![(start, Direction::Right)];
-    costs.insert((start, Direction::Right), 0);
-
-    while !nodes_to_visit.is_empty() {
-        let node = nodes_to_visit.remove(
-            nodes_to_visit
-                .iter()
-                .position_min_by_key(|x| costs.get(*x).unwrap_or(&u32::MAX))
-                .unwrap(),
-        );
-        let forward_node = (node.0.move_to(node.1), node.1);
-        let clockwise_node = (node.0, node.1.turn90());
-        let counter_clockwise_node = (node.0, node.1.turn270());
-        let neighbours = if *grid.get(forward_node.0).unwrap_or(&Tile::Wall) == Tile::Wall {
-            vec![(clockwise_node, 1000), (counter_clockwise_node, 1000)]
-        } else {
-            vec![
-                (forward_node, 1),
-                (clockwise_node, 1000),
-                (counter_clockwise_node, 1000),
-            ]
-        };
-        for (next_node, added_cost) in neighbours {
-            if !costs.contains_key(&next_node) {
-                nodes_to_visit.push(next_node);
+fn parse_input(file_contents: &str) -> Result<Grid<Tile>, ParseError> {
+    let grid = Grid::parse_from_string(file_contents, "'.', '#', 'S' or 'E'", |c| match c {
+        '.' => Some(Tile::Empty),
+        '#' => Some(Tile::Wall),
+        'S' => Some(Tile::Start),
+        'E' => Some(Tile::End),
+        _ => None,
+    })?;
+    // Errors point at the second marker, or behind the grid if there is none.
+    for (marker, name) in [('S', "start tile 'S'"), ('E', "end tile 'E'")] {
+        let mut found = file_contents
+            .lines()
+            .flat_map(|line| line.match_indices(marker).map(|(_, token)| token));
+        match (found.next(), found.next()) {
+            (None, _) => {
+                let grid_input = file_contents.trim_end();
+                let end = &grid_input[grid_input.len()..];
+                return Err(ParseError::at(file_contents, end, name));
             }
-            let next_potential = costs[&node].saturating_add(added_cost);
-            let current_best = costs.entry(next_node).or_insert(u32::MAX);
-            if next_potential <= *current_best {
-                prevs.entry(next_node).or_insert(Vec::new()).push(node);
-                *current_best = next_potential;
+            (Some(_), Some(second)) => {
+                return Err(ParseError::at(
+                    file_contents,
+                    second,
+                    format!("exactly one {name}"),
+                ))
             }
+            (Some(_), None) => {}
         }
     }
+    Ok(grid)
+}
+
+fn find_shortest_paths(grid: &Grid<Tile>) -> (ShortestPaths<PathNode, u32>, Vec<PathNode>) {
+    let start = grid
+        .find(&Tile::Start)
+        .exactly_one()
+        .ok()
+        .expect("the parser checks for exactly one start");
+    let end = grid
+        .find(&Tile::End)
+        .exactly_one()
+        .ok()
+        .expect("the parser checks for exactly one end");
+
+    let paths = dijkstra((start, Direction::Right), |&(pos, dir)| {
+        let forward_node = grid
+            .try_move(pos, dir)
+            .filter(|&pos| *grid.get(pos).unwrap() != Tile::Wall)
+            .map(|pos| ((pos, dir), 1));
+        [((pos, dir.turn90()), 1000), ((pos, dir.turn270()), 1000)]
+            .into_iter()
+            .chain(forward_node)
+    });
 
     let min_cost = Direction::all()
-        .map(|dir| *costs.get(&(end, dir)).unwrap_or(&u32::MAX))
         .into_iter()
-        .min()
-        .unwrap();
-    (prevs, min_cost)
+        .filter_map(|dir| paths.distance(&(end, dir)))
+        .min();
+    let best_ends = Direction::all()
+        .into_iter()
+        .map(|dir| (end, dir))
+        // Without a path to the end, there is no best end either.
+        .filter(|node| min_cost.is_some() && paths.distance(node) == min_cost)
+        .collect();
+    (paths, best_ends)
 }
 
-fn path_cost(path: &[PathNode]) -> u32 {
-    path.windows(2)
-        .map(|window| {
-            let (&a, &b) = window.iter().collect_tuple().unwrap();
-            if a.0 == b.0 {
-                1000
-            } else {
-                1
-            }
-        })
-        .sum()
+/// The maze with every tile on a best path highlighted.
+fn render_best_paths(grid: &Grid<Tile>) -> Frame {
+    let (paths, best_ends) = find_shortest_paths(grid);
+    let mut frame = grid.render(|_, &tile| match tile {
+        Tile::Wall => Cell::coloured(tile.into(), Rgb::GREY),
+        Tile::Start | Tile::End => Cell::coloured(tile.into(), Rgb::RED),
+        Tile::Empty => Cell::new(tile.into()),
+    });
+    frame.overlay(
+        paths
+            .on_shortest_paths(best_ends)
+            .into_iter()
+            .map(|node| node.0),
+        Rgb::YELLOW,
+    );
+    frame
 }
 
-fn collect_best_paths(
-    grid: &Grid<Tile>,
-    prevs: &HashMap<PathNode, Vec<PathNode>>,
-    min_cost: u32,
-) -> HashSet<Vec<PathNode>> {
-    fn collect_from(
-        node: PathNode,
-        prevs: &HashMap<PathNode, Vec<PathNode>>,
-    ) -> Vec<Vec<PathNode>> {
-        if let Some(previous_nodes) = prevs.get(&node) {
-            previous_nodes
-                .iter()
-                .flat_map(|prev_node| {
-                    collect_from(*prev_node, prevs).into_iter().map(|mut path| {
-                        path.push(node);
-                        path
-                    })
-                })
-                .collect()
-        } else {
-            vec![vec![node]]
-        }
+pub(crate) fn render(file_contents: &str) -> Result<Frame, ParseError> {
+    Ok(render_best_paths(&parse_input(file_contents)?))
+}
+
+impl Solution for Day16 {
+    type Input = Grid<Tile>;
+    type Answer1 = MaybeAnswer<u32>;
+    type Answer2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
 
-    let end = grid.find(&Tile::End).exactly_one().ok().unwrap();
-    let mut result = HashSet::new();
-    for dir in Direction::all() {
-        result.extend(collect_from((end, dir), prevs));
+    fn part1(input: &Self::Input) -> MaybeAnswer<u32> {
+        let (paths, best_ends) = find_shortest_paths(input);
+        best_ends.first().and_then(|end| paths.distance(end)).into()
+    }
+
+    fn part2(input: &Self::Input) -> MaybeAnswer<usize> {
+        let (paths, best_ends) = find_shortest_paths(input);
+        if best_ends.is_empty() {
+            return MaybeAnswer(None);
+        }
+        let on_a_best_path: HashSet<_> = paths
+            .on_shortest_paths(best_ends)
+            .into_iter()
+            .map(|node| node.0)
+            .collect();
+        MaybeAnswer(Some(on_a_best_path.len()))
     }
-    result
-        .into_iter()
-        .filter(|path| path_cost(path) == min_cost)
-        .collect()
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day16_input.txt");
-    let (prevs, shortest_path) = find_shortest_path(&input);
-    println!("Star 1: {:?}", shortest_path);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let best_paths = collect_best_paths(&input, &prevs, shortest_path);
-    let on_a_best_path: HashSet<_> = best_paths
-        .iter()
-        .flat_map(|p| p)
-        .map(|node| node.0)
-        .collect();
-    println!("Star 2: {}", on_a_best_path.len());
+    const EXAMPLE1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const EXAMPLE2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn part1_example1() {
+        let input = Day16::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day16::part1(&input), MaybeAnswer(Some(7036)));
+    }
+
+    #[test]
+    fn part1_example2() {
+        let input = Day16::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day16::part1(&input), MaybeAnswer(Some(11048)));
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = Day16::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day16::part2(&input), MaybeAnswer(Some(45)));
+    }
+
+    #[test]
+    fn part2_example2() {
+        let input = Day16::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day16::part2(&input), MaybeAnswer(Some(64)));
+    }
+
+    #[test]
+    fn parse_requires_one_start_and_one_end() {
+        let err = Day16::parse("#####\n#S.S#\n#..E#\n#####\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:4: expected exactly one start tile 'S', found \"S\""
+        );
+        let err = Day16::parse("#####\n#S..#\n#####\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:3:6: expected end tile 'E', found end of input"
+        );
+    }
+
+    #[test]
+    fn unreachable_end_has_no_answer() {
+        let input = Day16::parse("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(Day16::part1(&input).to_string(), "no answer");
+        assert_eq!(Day16::part2(&input).to_string(), "no answer");
+    }
+
+    #[test]
+    fn render_best_paths_example1() {
+        let input = Day16::parse(EXAMPLE1).unwrap();
+        let frame = render_best_paths(&input);
+        assert_eq!(frame.to_string(), EXAMPLE1);
+        assert_eq!(frame.ansi().matches("\x1b[48;2").count(), 45);
+    }
 }
@@ -0,0 +1,422 @@
+use crate::registry::ExportFormat;
+use aoc_common::{parse_number, split_once, MaybeAnswer, ParseError, Solution};
+use itertools::Itertools;
+use std::fmt::Write;
+
+pub(crate) struct Day17;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    fn from_bits(bits: u8) -> Opcode {
+        [
+            Opcode::Adv,
+            Opcode::Bxl,
+            Opcode::Bst,
+            Opcode::Jnz,
+            Opcode::Bxc,
+            Opcode::Out,
+            Opcode::Bdv,
+            Opcode::Cdv,
+        ][bits as usize]
+    }
+
+    fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    /// Whether the operand is a combo operand, which reads a register for 4 to 6.
+    fn takes_combo(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Computer {
+    registers: [u64; 3],
+    program: Vec<u8>,
+}
+
+fn parse_input(file_contents: &str) -> Result<Computer, ParseError> {
+    let (registers, program) = split_once(file_contents, file_contents, "\n\n")?;
+    let mut registers = registers.lines();
+    let mut parse_register = |name| {
+        let line = registers
+            .next()
+            .unwrap_or(&file_contents[file_contents.len()..]);
+        let prefix = format!("Register {name}: ");
+        let value = line
+            .strip_prefix(&prefix)
+            .ok_or_else(|| ParseError::at(file_contents, line, format!("\"{prefix}..\"")))?;
+        parse_number(file_contents, value)
+    };
+    let registers = [
+        parse_register('A')?,
+        parse_register('B')?,
+        parse_register('C')?,
+    ];
+    let program = program.trim_end();
+    let tokens = program
+        .strip_prefix("Program: ")
+        .ok_or_else(|| ParseError::at(file_contents, program, "\"Program: ..\""))?
+        .split(',')
+        .collect_vec();
+    let program = tokens
+        .iter()
+        .map(|bits| match parse_number(file_contents, bits)? {
+            value @ 0..=7 => Ok(value),
+            _ => Err(ParseError::at(file_contents, bits, "a 3-bit number")),
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    // Combo operand 7 is reserved and does not appear in valid programs.
+    for (i, instruction) in program.chunks_exact(2).enumerate() {
+        if Opcode::from_bits(instruction[0]).takes_combo() && instruction[1] == 7 {
+            return Err(ParseError::at(
+                file_contents,
+                tokens[2 * i + 1],
+                "a combo operand from 0 to 6",
+            ));
+        }
+    }
+    Ok(Computer { registers, program })
+}
+
+/// Steps after which a program is taken to loop forever. The puzzle programs run one round of
+/// about 8 instructions per 3 bits of A, so they halt long before.
+const MAX_STEPS: usize = 1_000_000;
+
+/// The state of the computer while it runs a program.
+#[derive(Debug, Clone)]
+struct Cpu<'a> {
+    program: &'a [u8],
+    registers: [u64; 3],
+    instruction_pointer: usize,
+    output: Vec<u8>,
+}
+
+impl<'a> Cpu<'a> {
+    fn new(program: &'a [u8], registers: [u64; 3]) -> Cpu<'a> {
+        Cpu {
+            program,
+            registers,
+            instruction_pointer: 0,
+            output: Vec::new(),
+        }
+    }
+
+    fn combo(&self, operand: u8) -> u64 {
+        match operand {
+            0..=3 => operand as u64,
+            4..=6 => self.registers[operand as usize - 4],
+            _ => unreachable!("the parser rejects the reserved combo operand {operand}"),
+        }
+    }
+
+    /// Executes the instruction at the instruction pointer, returns `false` if the program has
+    /// halted instead.
+    fn step(&mut self) -> bool {
+        let (Some(&opcode), Some(&operand)) = (
+            self.program.get(self.instruction_pointer),
+            self.program.get(self.instruction_pointer + 1),
+        ) else {
+            return false;
+        };
+        self.instruction_pointer += 2;
+        let op = Opcode::from_bits(opcode);
+        let value = if op.takes_combo() {
+            self.combo(operand)
+        } else {
+            operand as u64
+        };
+        let [a, b, c] = &mut self.registers;
+        // Shifting by 64 or more bits leaves nothing.
+        let shifted = u32::try_from(value)
+            .ok()
+            .and_then(|shift| a.checked_shr(shift))
+            .unwrap_or(0);
+        match op {
+            Opcode::Adv => *a = shifted,
+            Opcode::Bxl => *b ^= value,
+            Opcode::Bst => *b = value % 8,
+            Opcode::Jnz if *a != 0 => self.instruction_pointer = value as usize,
+            Opcode::Jnz => {}
+            Opcode::Bxc => *b ^= *c,
+            Opcode::Out => self.output.push((value % 8) as u8),
+            Opcode::Bdv => *b = shifted,
+            Opcode::Cdv => *c = shifted,
+        }
+        true
+    }
+
+    /// The output of the program, `None` if it does not halt within [`MAX_STEPS`] steps.
+    fn run(mut self) -> Option<Vec<u8>> {
+        for _ in 0..MAX_STEPS {
+            if !self.step() {
+                return Some(self.output);
+            }
+        }
+        None
+    }
+}
+
+/// Operand of an instruction as it is read: literal, or a register for a combo operand.
+fn operand_name(opcode: Opcode, operand: u8) -> String {
+    match operand {
+        4..=6 if opcode.takes_combo() => ["A", "B", "C"][operand as usize - 4].to_string(),
+        _ => operand.to_string(),
+    }
+}
+
+/// One instruction per line with its address, mnemonic and what it does. A trailing opcode
+/// without operand is shown as data.
+fn disassemble(program: &[u8]) -> String {
+    let mut listing = String::new();
+    for (address, chunk) in program.chunks(2).enumerate() {
+        let address = address * 2;
+        let &[opcode, operand] = chunk else {
+            writeln!(listing, "{address:3}: .data {}", chunk[0]).unwrap();
+            continue;
+        };
+        let op = Opcode::from_bits(opcode);
+        let arg = operand_name(op, operand);
+        let effect = match op {
+            Opcode::Adv => format!("A = A >> {arg}"),
+            Opcode::Bxl => format!("B = B ^ {arg}"),
+            Opcode::Bst => format!("B = {arg} % 8"),
+            Opcode::Jnz => format!("if A != 0 goto {arg}"),
+            Opcode::Bxc => "B = B ^ C".to_string(),
+            Opcode::Out => format!("output {arg} % 8"),
+            Opcode::Bdv => format!("B = A >> {arg}"),
+            Opcode::Cdv => format!("C = A >> {arg}"),
+        };
+        writeln!(
+            listing,
+            "{address:3}: {} {arg:<2} ; {effect}",
+            op.mnemonic()
+        )
+        .unwrap();
+    }
+    listing
+}
+
+/// The control flow of the program as a Graphviz graph, a jump is a dashed edge.
+fn to_dot(program: &[u8]) -> String {
+    let mut dot = String::from("digraph program {\n    node [shape=box, fontname=monospace];\n");
+    let instructions = program.len() / 2;
+    for (i, (&opcode, &operand)) in program.iter().tuples().enumerate() {
+        let op = Opcode::from_bits(opcode);
+        let label = format!("{}: {} {}", i * 2, op.mnemonic(), operand_name(op, operand));
+        writeln!(dot, "    i{i} [label=\"{label}\"];").unwrap();
+        if i + 1 < instructions {
+            writeln!(dot, "    i{i} -> i{};", i + 1).unwrap();
+        }
+        if op == Opcode::Jnz && operand % 2 == 0 && (operand as usize) < program.len() {
+            writeln!(dot, "    i{i} -> i{} [style=dashed];", operand / 2).unwrap();
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+pub(crate) fn export(
+    file_contents: &str,
+    format: ExportFormat,
+) -> Result<Option<String>, ParseError> {
+    let computer = parse_input(file_contents)?;
+    Ok(match format {
+        ExportFormat::Dot => Some(to_dot(&computer.program)),
+        ExportFormat::Text => Some(disassemble(&computer.program)),
+        ExportFormat::Report => None,
+    })
+}
+
+/// Smallest value of register A for which the program outputs itself (part 2).
+///
+/// This relies on the shape all puzzle programs have: a single loop that ends with `jnz 0`,
+/// shifts A right by 3 bits once per round with `adv 3` and outputs one number computed from
+/// the lowest bits of A. The last output then only depends on the highest 3 bits of A, so A is
+/// built from the end of the program, 3 bits per output, and backtracks where no bits fit.
+fn quine_register(computer: &Computer) -> Option<u64> {
+    let program = &computer.program;
+    let instructions = program.chunks(2).collect_vec();
+    let shifts = instructions
+        .iter()
+        .filter(|instruction| instruction[0] == Opcode::Adv as u8)
+        .collect_vec();
+    if shifts != [&[Opcode::Adv as u8, 3]] || instructions.last() != Some(&&[3, 0][..]) {
+        return None;
+    }
+    extend_quine(computer, 0, program.len())
+}
+
+/// Appends 3 bits at a time to `a`, which already makes the program output everything from
+/// `start` on, until it outputs the whole program.
+fn extend_quine(computer: &Computer, a: u64, start: usize) -> Option<u64> {
+    if start == 0 {
+        return Some(a);
+    }
+    let [_, b, c] = computer.registers;
+    (0..8).find_map(|bits| {
+        let a = a << 3 | bits;
+        let output = Cpu::new(&computer.program, [a, b, c]).run();
+        (output.as_deref() == Some(&computer.program[start - 1..]))
+            .then(|| extend_quine(computer, a, start - 1))
+            .flatten()
+    })
+}
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type Answer1 = MaybeAnswer<String>;
+    type Answer2 = MaybeAnswer<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    /// No answer if the program does not halt.
+    fn part1(input: &Self::Input) -> MaybeAnswer<String> {
+        Cpu::new(&input.program, input.registers)
+            .run()
+            .map(|output| output.iter().join(","))
+            .into()
+    }
+
+    /// No answer if the program does not shift A by 3 bits per output.
+    fn part2(input: &Self::Input) -> MaybeAnswer<u64> {
+        quine_register(input).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const QUINE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part1(&input).to_string(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(QUINE).unwrap();
+        assert_eq!(Day17::part2(&input), MaybeAnswer(Some(117440)));
+    }
+
+    #[test]
+    fn part2_needs_a_shift_by_3_bits() {
+        let input = Day17::parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::part2(&input).to_string(), "no answer");
+    }
+
+    #[test]
+    fn endless_loop_has_no_output() {
+        let input = Day17::parse(&EXAMPLE.replace("0,1,5,4,3,0", "5,4,3,0")).unwrap();
+        assert_eq!(Day17::part1(&input).to_string(), "no answer");
+    }
+
+    #[test]
+    fn instructions() {
+        let run = |registers, program: &[u8]| {
+            let mut cpu = Cpu::new(program, registers);
+            while cpu.step() {}
+            (cpu.registers, cpu.output)
+        };
+        assert_eq!(run([0, 0, 9], &[2, 6]).0[1], 1);
+        assert_eq!(run([10, 0, 0], &[5, 0, 5, 1, 5, 4]).1, [0, 1, 2]);
+        let (registers, output) = run([2024, 0, 0], &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(
+            (registers[0], output),
+            (0, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0])
+        );
+        assert_eq!(run([0, 29, 0], &[1, 7]).0[1], 26);
+        assert_eq!(run([0, 2024, 43690], &[4, 0]).0[1], 44354);
+    }
+
+    #[test]
+    fn quine_of_a_typical_program() {
+        let input = "\
+Register A: 0
+Register B: 0
+Register C: 0
+
+Program: 2,4,1,3,7,5,4,1,1,3,0,3,5,5,3,0
+";
+        let computer = Day17::parse(input).unwrap();
+        let a = Day17::part2(&computer).0.unwrap();
+        assert_eq!(
+            Cpu::new(&computer.program, [a, 0, 0]).run(),
+            Some(computer.program)
+        );
+    }
+
+    #[test]
+    fn disassembles_with_registers_for_combo_operands() {
+        let listing = export(QUINE, ExportFormat::Text).unwrap().unwrap();
+        assert_eq!(
+            listing,
+            "  0: adv 3  ; A = A >> 3\n  2: out A  ; output A % 8\n  4: jnz 0  ; if A != 0 goto 0\n"
+        );
+        let dot = export(QUINE, ExportFormat::Dot).unwrap().unwrap();
+        assert!(dot.contains("    i2 [label=\"4: jnz 0\"];\n    i2 -> i0 [style=dashed];\n"));
+    }
+
+    #[test]
+    fn rejects_numbers_beyond_three_bits() {
+        let err = Day17::parse(&EXAMPLE.replace("5,4", "5,8")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:5:16: expected a 3-bit number, found \"8\""
+        );
+    }
+
+    #[test]
+    fn rejects_the_reserved_combo_operand() {
+        let err = Day17::parse(&EXAMPLE.replace("5,4", "5,7")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:5:16: expected a combo operand from 0 to 6, found \"7\""
+        );
+        // Literal operands may be 7.
+        assert!(Day17::parse(&EXAMPLE.replace("5,4", "1,7")).is_ok());
+    }
+}
@@ -1,112 +1,122 @@
 #![allow(dead_code)]
 
-use crate::grid::{Direction, Grid, Position};
-use itertools::Itertools;
-use std::collections::{HashSet, VecDeque};
-use std::fs;
+use crate::grid::{Direction, GridRead, Point, Position};
+use crate::sparse_grid::SparseGrid;
+use aoc_common::path::bfs;
+use aoc_common::{parse_number, split_once, MaybeAnswer, ParseError, Solution, Unsolved};
 
-const GRID_WIDTH: u32 = 71;
-const GRID_HEIGHT: u32 = 71;
+pub(crate) struct Day18;
 
-fn parse_input(filename: &str) -> Vec<Position> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+const GRID_SIZE: u32 = 71;
+const FALLEN_BYTES: usize = 1024;
+
+fn parse_input(file_contents: &str) -> Result<Vec<Position>, ParseError> {
     file_contents
         .lines()
         .map(|line| {
-            let (x, y) = line.split(",").collect_tuple().unwrap();
-            Position {
-                x: x.parse().unwrap(),
-                y: y.parse().unwrap(),
-            }
+            let (x, y) = split_once(file_contents, line, ",")?;
+            let coordinate = |number| match parse_number(file_contents, number)? {
+                value @ 0..GRID_SIZE => Ok(value),
+                _ => Err(ParseError::at(
+                    file_contents,
+                    number,
+                    format!("a coordinate below {GRID_SIZE}"),
+                )),
+            };
+            Ok(Position {
+                x: coordinate(x)?,
+                y: coordinate(y)?,
+            })
         })
         .collect()
 }
 
-fn star1(corrupted_bytes: &[Position]) {
-    let grid_corrupted = Grid {
-        width: GRID_WIDTH,
-        height: GRID_HEIGHT,
-        entities: (0..GRID_WIDTH)
-            .flat_map(|x| {
-                (0..GRID_HEIGHT).map(move |y| corrupted_bytes.contains(&Position { x, y }))
-            })
-            .collect(),
-    };
-    let mut grid_min_distance = Grid {
-        width: GRID_WIDTH,
-        height: GRID_HEIGHT,
-        entities: (0..GRID_WIDTH)
-            .flat_map(|x| {
-                (0..GRID_HEIGHT).map(move |y| if x == 0 && y == 0 { 0 } else { u32::MAX })
-            })
-            .collect(),
-    };
-    let mut grid_predecessor = Grid {
-        width: GRID_WIDTH,
-        height: GRID_HEIGHT,
-        entities: (0..GRID_WIDTH)
-            .flat_map(|_| (0..GRID_HEIGHT).map(|_| Direction::Up))
-            .collect(),
-    };
+fn shortest_path_length(corrupted_bytes: &[Position], grid_size: u32) -> Option<u32> {
+    let mut corrupted = SparseGrid::bounded(grid_size, grid_size);
+    for &byte in corrupted_bytes {
+        corrupted.insert(byte.into(), ());
+    }
+    let corrupted = &corrupted;
+
+    let starting_pos = Point::new(0, 0);
+    let goal_pos = Point::new(grid_size as i32 - 1, grid_size as i32 - 1);
+    let paths = bfs(starting_pos, |&node| {
+        Direction::all()
+            .into_iter()
+            .filter_map(move |direction| corrupted.try_move(node, direction))
+            .filter(move |&next_node| corrupted.get(next_node).is_none())
+    });
+    paths.distance(&goal_pos).map(|distance| distance as u32)
+}
 
-    let starting_pos = Position { x: 0, y: 0 };
-    let goal_pos = Position {
-        x: GRID_WIDTH - 1,
-        y: GRID_HEIGHT - 1,
-    };
-    let mut nodes_to_visit = VecDeque::from([starting_pos]);
-    let mut visited_nodes = HashSet::new();
+impl Solution for Day18 {
+    type Input = Vec<Position>;
+    type Answer1 = MaybeAnswer<u32>;
+    type Answer2 = Unsolved;
 
-    while let Some(node) = nodes_to_visit.pop_back() {
-        if node == goal_pos {
-            break;
-        }
-        if visited_nodes.get(&node).is_some() {
-            continue;
-        }
-        visited_nodes.insert(node);
-        for dir in Direction::all() {
-            let next_node = node.move_to(dir);
-            if let Some(next_node_distance) = grid_min_distance.get(next_node) {
-                nodes_to_visit.push_front(next_node);
-                if *next_node_distance > grid_min_distance.get(node).unwrap() + 1 {
-                    grid_min_distance.set(next_node, grid_min_distance.get(node).unwrap() + 1);
-                    grid_predecessor.set(next_node, dir);
-                }
-            }
-        }
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
     }
 
-    let shortest_path: HashSet<_> = {
-        let mut path = vec![goal_pos];
-        while *path.last().unwrap() != starting_pos {
-            println!("{:?}", path);
-            let pos = *path.last().unwrap();
-            path.push(pos.move_to(*grid_predecessor.get(pos).expect(&format!("{:?}", pos))));
-        }
-        path
+    fn part1(input: &Self::Input) -> MaybeAnswer<u32> {
+        shortest_path_length(&input[..FALLEN_BYTES.min(input.len())], GRID_SIZE).into()
+    }
+
+    fn part2(_input: &Self::Input) -> Unsolved {
+        Unsolved
     }
-        .into_iter()
-        .collect();
-    println!(
-        "{}",
-        grid_corrupted.pretty_print(|position, corrupted| {
-            if *corrupted {
-                '#'
-            } else {
-                if shortest_path.contains(&position) {
-                    char::from(*grid_predecessor.get(position).unwrap())
-                } else {
-                    '.'
-                }
-            }
-        })
-    );
-    //println!("Star 1: {}", path_length);
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day18_input.txt");
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(shortest_path_length(&input[..12], 7), Some(22));
+    }
+
+    #[test]
+    fn part1_with_fewer_bytes_than_fall() {
+        let input = Day18::parse("1,0\n0,2\n").unwrap();
+        assert_eq!(Day18::part1(&input), MaybeAnswer(Some(2 * (GRID_SIZE - 1))));
+    }
 
-    star1(&input[..1024]);
+    #[test]
+    fn rejects_bytes_outside_of_the_memory_space() {
+        let err = Day18::parse("1,2\n80,3\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:1: expected a coordinate below 71, found \"80\""
+        );
+    }
 }
@@ -1,13 +1,18 @@
 #![allow(dead_code)]
 
+use aoc_common::{parse_number, ParseError, Solution};
 use std::collections::HashSet;
-use std::fs;
 
-fn parse_input(filename: &str) -> Vec<Vec<i16>> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+pub(crate) struct Day2;
+
+fn parse_input(file_contents: &str) -> Result<Vec<Vec<i16>>, ParseError> {
     file_contents
         .lines()
-        .map(|line| line.split(" ").map(|x| x.parse().unwrap()).collect())
+        .map(|line| {
+            line.split(" ")
+                .map(|x| parse_number(file_contents, x))
+                .collect()
+        })
         .collect()
 }
 
@@ -50,13 +55,49 @@ fn is_safe(level: &[i16], dampening_limit: usize) -> bool {
     false
 }
 
-pub fn main() {
-    let input = parse_input("day2_input.txt");
+impl Solution for Day2 {
+    type Input = Vec<Vec<i16>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let safe_levels1: HashSet<_> = input.iter().filter(|level| is_safe(level, 0)).collect();
+        safe_levels1.len()
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        let safe_levels2: HashSet<_> = input.iter().filter(|level| is_safe(level, 1)).collect();
+        // Mir ist aufgefallen dass meine Lösung für Tag 2 gar nicht richtig funktioniert, z.B: für "88 91 90 91 93", aber scheinbar hatte ich Glück mit dem Input :^)
+        safe_levels2.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let safe_levels1: HashSet<_> = input.iter().filter(|level| is_safe(level, 0)).collect();
-    let safe_levels2: HashSet<_> = input.iter().filter(|level| is_safe(level, 1)).collect();
-    // Mir ist aufgefallen dass meine Lösung für Tag 2 gar nicht richtig funktioniert, z.B: für "88 91 90 91 93", aber scheinbar hatte ich Glück mit dem Input :^)
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
 
-    println!("Star 1: {:?}", safe_levels1.len());
-    println!("Star 2: {:?}", safe_levels2.len());
+    #[test]
+    fn part1_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part1(&input), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day2::parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::part2(&input), 4);
+    }
 }
@@ -1,11 +1,14 @@
 #![allow(dead_code)]
 
 use crate::grid::Position;
+use aoc_common::{parse_chars, ParseError, Solution};
 use itertools::Itertools;
-use std::fs;
+use std::collections::HashMap;
+
+pub(crate) struct Day21;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
-enum KeypadTile {
+pub(crate) enum KeypadTile {
     A,
     Blank,
     Num0,
@@ -21,6 +24,22 @@ enum KeypadTile {
 }
 
 impl KeypadTile {
+    fn digit(&self) -> Option<u64> {
+        match self {
+            KeypadTile::A | KeypadTile::Blank => None,
+            KeypadTile::Num0 => Some(0),
+            KeypadTile::Num1 => Some(1),
+            KeypadTile::Num2 => Some(2),
+            KeypadTile::Num3 => Some(3),
+            KeypadTile::Num4 => Some(4),
+            KeypadTile::Num5 => Some(5),
+            KeypadTile::Num6 => Some(6),
+            KeypadTile::Num7 => Some(7),
+            KeypadTile::Num8 => Some(8),
+            KeypadTile::Num9 => Some(9),
+        }
+    }
+
     fn position(&self) -> Position {
         match self {
             KeypadTile::A => Position { x: 2, y: 3 },
@@ -39,27 +58,29 @@ impl KeypadTile {
     }
 }
 
-impl From<char> for KeypadTile {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for KeypadTile {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'A' => KeypadTile::A,
-            ' ' => KeypadTile::Blank,
-            '0' => KeypadTile::Num0,
-            '1' => KeypadTile::Num1,
-            '2' => KeypadTile::Num2,
-            '3' => KeypadTile::Num3,
-            '4' => KeypadTile::Num4,
-            '5' => KeypadTile::Num5,
-            '6' => KeypadTile::Num6,
-            '7' => KeypadTile::Num7,
-            '8' => KeypadTile::Num8,
-            '9' => KeypadTile::Num9,
-            _ => unreachable!(),
+            'A' => Ok(KeypadTile::A),
+            ' ' => Ok(KeypadTile::Blank),
+            '0' => Ok(KeypadTile::Num0),
+            '1' => Ok(KeypadTile::Num1),
+            '2' => Ok(KeypadTile::Num2),
+            '3' => Ok(KeypadTile::Num3),
+            '4' => Ok(KeypadTile::Num4),
+            '5' => Ok(KeypadTile::Num5),
+            '6' => Ok(KeypadTile::Num6),
+            '7' => Ok(KeypadTile::Num7),
+            '8' => Ok(KeypadTile::Num8),
+            '9' => Ok(KeypadTile::Num9),
+            _ => Err(value),
         }
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 enum DpadTile {
     A,
     Blank,
@@ -69,64 +90,209 @@ enum DpadTile {
     Down,
 }
 
-fn keypad_sequence_to_directions(start: KeypadTile, sequence: &[KeypadTile]) -> Vec<DpadTile> {
-    let mut result = Vec::new();
-    let mut current_pos = start.position();
-    for target_tile in sequence {
-        let target_pos = target_tile.position();
-        while current_pos != target_pos {
-            let next_dpad = if current_pos.x != target_pos.x {
-                match (current_pos.x, target_pos.x) {
-                    (0, 1) => DpadTile::Right,
-                    (0, 2) => DpadTile::Left,
-                    (1, 0) => DpadTile::Left,
-                    (1, 2) => DpadTile::Right,
-                    (2, 0) => DpadTile::Right,
-                    (2, 1) => DpadTile::Left,
-                    _ => unreachable!(),
-                }
-            } else {
-                match (current_pos.y, target_pos.y) {
-                    (0, 1) => DpadTile::Up,
-                    (0, 2) => DpadTile::Up,
-                    (0, 3) => DpadTile::Up,
-                    (1, 0) => DpadTile::Up,
-                    (1, 2) => DpadTile::Up,
-                    (1, 3) => DpadTile::Up,
-                    (2, 0) => DpadTile::Up,
-                    (2, 1) => DpadTile::Up,
-                    (2, 3) => DpadTile::Up,
-                    (3, 0) => DpadTile::Up,
-                    (3, 1) => DpadTile::Up,
-                    (3, 2) => DpadTile::Up,
-                    _ => unreachable!(),
-                }
-            };
-            result.push(next_dpad);
-            match next_dpad {
-                DpadTile::Left => current_pos.x = (current_pos.x + 2) % 3,
-                DpadTile::Right => current_pos.x = (current_pos.x + 1) % 3,
-                DpadTile::Up => current_pos.y = (current_pos.y + 3) % 4,
-                DpadTile::Down => current_pos.y = (current_pos.y + 1) % 4,
-                _ => unreachable!(),
-            };
+impl DpadTile {
+    /// The buttons, without the gap.
+    const BUTTONS: [DpadTile; 5] = [
+        DpadTile::A,
+        DpadTile::Left,
+        DpadTile::Right,
+        DpadTile::Up,
+        DpadTile::Down,
+    ];
+
+    fn position(&self) -> Position {
+        match self {
+            DpadTile::Blank => Position { x: 0, y: 0 },
+            DpadTile::Up => Position { x: 1, y: 0 },
+            DpadTile::A => Position { x: 2, y: 0 },
+            DpadTile::Left => Position { x: 0, y: 1 },
+            DpadTile::Down => Position { x: 1, y: 1 },
+            DpadTile::Right => Position { x: 2, y: 1 },
         }
-        result.push(DpadTile::A);
     }
-    result
 }
 
-fn parse_input(filename: &str) -> Vec<Vec<KeypadTile>> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+/// Presses on a directional keypad that move a robot arm from `from` to `to` and press the
+/// button there, without pointing the arm at the `gap`. Only the two ways with a single turn are
+/// candidates, any other way needs more presses on every keypad further up the chain.
+fn arm_moves(from: Position, to: Position, gap: Position) -> Vec<Vec<DpadTile>> {
+    let horizontal = if to.x > from.x {
+        DpadTile::Right
+    } else {
+        DpadTile::Left
+    };
+    let vertical = if to.y > from.y {
+        DpadTile::Down
+    } else {
+        DpadTile::Up
+    };
+    let horizontal = vec![horizontal; from.x.abs_diff(to.x) as usize];
+    let vertical = vec![vertical; from.y.abs_diff(to.y) as usize];
+    let mut moves = Vec::new();
+    if Position::new(to.x, from.y) != gap {
+        moves.push([&horizontal[..], &vertical, &[DpadTile::A]].concat());
+    }
+    if Position::new(from.x, to.y) != gap {
+        moves.push([&vertical[..], &horizontal, &[DpadTile::A]].concat());
+    }
+    moves.dedup();
+    moves
+}
+
+/// Presses by the human for pressing `to` after `from` on a directional keypad.
+type CostTable = HashMap<(DpadTile, DpadTile), u64>;
+
+/// Presses by the human for entering `sequence` on the directional keypad that `costs` is for.
+/// The arm starts at `A`, just like it is back at `A` whenever the keypad below it was pressed.
+fn sequence_cost(costs: &CostTable, sequence: &[DpadTile]) -> u64 {
+    std::iter::once(&DpadTile::A)
+        .chain(sequence)
+        .tuple_windows()
+        .map(|(&from, &to)| costs[&(from, to)])
+        .sum()
+}
+
+/// Cheapest way to move an arm from `from` to `to` and press, operated through `costs`.
+fn move_cost(costs: &CostTable, from: Position, to: Position, gap: Position) -> u64 {
+    arm_moves(from, to, gap)
+        .iter()
+        .map(|moves| sequence_cost(costs, moves))
+        .min()
+        .unwrap()
+}
+
+/// Cost table of the directional keypad operated through `robots` more directional keypads in
+/// between it and the human. The human presses every button with a single press, every robot
+/// is built from the table of the keypad it is operated with.
+fn dpad_costs(robots: usize) -> CostTable {
+    let pairs = || {
+        DpadTile::BUTTONS
+            .into_iter()
+            .cartesian_product(DpadTile::BUTTONS)
+    };
+    let mut costs: CostTable = pairs().map(|pair| (pair, 1)).collect();
+    for _ in 0..robots {
+        costs = pairs()
+            .map(|(from, to)| {
+                let gap = DpadTile::Blank.position();
+                let cost = move_cost(&costs, from.position(), to.position(), gap);
+                ((from, to), cost)
+            })
+            .collect();
+    }
+    costs
+}
+
+/// Presses by the human for entering `code` on the numeric keypad through `costs`.
+fn code_presses(code: &[KeypadTile], costs: &CostTable) -> u64 {
+    std::iter::once(&KeypadTile::A)
+        .chain(code)
+        .tuple_windows()
+        .map(|(from, to)| {
+            let gap = KeypadTile::Blank.position();
+            move_cost(costs, from.position(), to.position(), gap)
+        })
+        .sum()
+}
+
+/// The digits of a code as a number, e.g. 29 for `029A`.
+fn numeric_part(code: &[KeypadTile]) -> u64 {
+    code.iter()
+        .filter_map(KeypadTile::digit)
+        .fold(0, |number, digit| number * 10 + digit)
+}
+
+/// Sum of the complexities of the codes when the numeric keypad is operated through a chain of
+/// `robots` robots on directional keypads.
+fn complexity_sum(codes: &[Vec<KeypadTile>], robots: usize) -> u64 {
+    // The human's keypad is the first of the chain, the robots operate all but the last one.
+    let costs = dpad_costs(robots);
+    codes
+        .iter()
+        .map(|code| code_presses(code, &costs) * numeric_part(code))
+        .sum()
+}
+
+fn parse_input(file_contents: &str) -> Result<Vec<Vec<KeypadTile>>, ParseError> {
     file_contents
         .lines()
-        .map(|line| line.chars().map(|c| KeypadTile::from(c)).collect_vec())
-        .collect_vec()
+        .map(|line| {
+            parse_chars(file_contents, line, "a keypad key", |c| {
+                KeypadTile::try_from(c)
+                    .ok()
+                    .filter(|&tile| tile != KeypadTile::Blank)
+            })
+        })
+        .collect()
+}
+
+impl Solution for Day21 {
+    type Input = Vec<Vec<KeypadTile>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        complexity_sum(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        complexity_sum(input, 25)
+    }
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day21_input.txt");
-    println!("{:?}", input);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn arm_moves_avoid_the_gap() {
+        let gap = KeypadTile::Blank.position();
+        let moves = arm_moves(KeypadTile::A.position(), KeypadTile::Num1.position(), gap);
+        use DpadTile::*;
+        assert_eq!(moves, [vec![Up, Left, Left, A]]);
+    }
+
+    #[test]
+    fn presses_per_layer() {
+        let code = &Day21::parse("029A").unwrap()[0];
+        assert_eq!(
+            code_presses(code, &dpad_costs(0)),
+            "<A^A>^^AvvvA".len() as u64
+        );
+        assert_eq!(
+            code_presses(code, &dpad_costs(1)),
+            "v<<A>>^A<A>AvA<^AA>A<vAAA>^A".len() as u64
+        );
+        assert_eq!(code_presses(code, &dpad_costs(2)), 68);
+        assert_eq!(numeric_part(code), 29);
+    }
 
-    println!("{:?}", keypad_sequence_to_directions(KeypadTile::A, &input[0]));
+    #[test]
+    fn part1_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part1(&input), 126384);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day21::parse(EXAMPLE).unwrap();
+        assert_eq!(Day21::part2(&input), 154115708116294);
+    }
+
+    #[test]
+    fn blank_is_not_a_key() {
+        assert!(Day21::parse("0 1A\n").is_err());
+    }
 }
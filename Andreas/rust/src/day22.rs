@@ -1,18 +1,20 @@
 #![allow(dead_code)]
 
+use aoc_common::{parse_number, ParseError, Solution};
 use itertools::Itertools;
 use std::cmp::max;
 use std::collections::HashMap;
-use std::fs;
+
+pub(crate) struct Day22;
 
 const SECRET_NUMBER_PRUNE: u64 = 2u64.pow(24) - 1;
-const STEPS: usize = 2000;
+// the initial secret number followed by 2000 new ones
+const STEPS: usize = 2001;
 
-fn parse_input(filename: &str) -> Vec<u64> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+fn parse_input(file_contents: &str) -> Result<Vec<u64>, ParseError> {
     file_contents
         .lines()
-        .map(|line| line.parse().unwrap())
+        .map(|line| parse_number(file_contents, line))
         .collect()
 }
 
@@ -22,15 +24,8 @@ fn evolve_secret_number(n: u64) -> u64 {
     ((b << 11) ^ b) & SECRET_NUMBER_PRUNE
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day22_input.txt");
-
-    // println!("{:?}", input);
-    // for n in input {
-    //     println!("{}: {}", n, evolve_secret_number_multi(n, 2000));
-    // }
-
-    let buyer_secret_numbers: Vec<[u64; STEPS]> = input
+fn secret_number_sequences(input: &[u64]) -> Vec<[u64; STEPS]> {
+    input
         .iter()
         .map(|n| {
             (0..STEPS)
@@ -43,7 +38,19 @@ pub(crate) fn main() {
                 .try_into()
                 .unwrap()
         })
-        .collect_vec();
+        .collect_vec()
+}
+
+fn star1(input: &[u64]) -> u64 {
+    let buyer_secret_numbers = secret_number_sequences(input);
+    buyer_secret_numbers
+        .iter()
+        .map(|secrets| secrets.last().unwrap())
+        .sum()
+}
+
+fn star2(input: &[u64]) -> u64 {
+    let buyer_secret_numbers = secret_number_sequences(input);
     let buyer_prices: Vec<[u64; STEPS]> = buyer_secret_numbers
         .iter()
         .map(|secrets| secrets.map(|i| i % 10))
@@ -65,14 +72,6 @@ pub(crate) fn main() {
         })
         .collect_vec();
 
-    println!(
-        "Star 1: {}",
-        buyer_secret_numbers
-            .iter()
-            .map(|secrets| secrets.last().unwrap())
-            .sum::<u64>()
-    );
-
     let buyer_prices_for_sequence = buyer_change_sequences
         .iter()
         .enumerate()
@@ -88,7 +87,7 @@ pub(crate) fn main() {
 
     let all_possible_change_sequences = buyer_prices_for_sequence
         .iter()
-        .flat_map(|map| map.keys().map(|k| *k))
+        .flat_map(|map| map.keys().copied())
         .unique()
         .collect_vec();
 
@@ -100,5 +99,54 @@ pub(crate) fn main() {
         }
         best_price_sum = max(price_sum, best_price_sum);
     }
-    println!("Star 2: {}", best_price_sum);
+    best_price_sum
+}
+
+impl Solution for Day22 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        star1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        star2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+1
+10
+100
+2024
+";
+
+    const EXAMPLE2: &str = "\
+1
+2
+3
+2024
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day22::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day22::part1(&input), 37327623);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day22::part2(&input), 23);
+    }
 }
@@ -1,12 +1,14 @@
 #![allow(dead_code)]
 
+use aoc_common::{split_once, ParseError, Solution};
 use itertools::Itertools;
 use std::cmp::{max, min};
 use std::collections::HashSet;
-use std::fs;
+
+pub(crate) struct Day23;
 
 #[derive(Debug)]
-struct NetworkMap {
+pub(crate) struct NetworkMap {
     nodes: Vec<String>,
     edges: HashSet<(usize, usize)>,
 }
@@ -17,12 +19,11 @@ impl NetworkMap {
     }
 }
 
-fn parse_input(filename: &str) -> NetworkMap {
-    let file_contents = fs::read_to_string(filename).unwrap();
+fn parse_input(file_contents: &str) -> Result<NetworkMap, ParseError> {
     let connections: Vec<(&str, &str)> = file_contents
         .lines()
-        .map(|line| line.split("-").collect_tuple().unwrap())
-        .collect_vec();
+        .map(|line| split_once(file_contents, line, "-"))
+        .collect::<Result<_, _>>()?;
     let nodes = connections
         .iter()
         .flat_map(|(l, r)| [(*l).to_owned(), (*r).to_owned()])
@@ -36,7 +37,7 @@ fn parse_input(filename: &str) -> NetworkMap {
             (min(li, ri), max(li, ri))
         })
         .collect();
-    NetworkMap { nodes, edges }
+    Ok(NetworkMap { nodes, edges })
 }
 
 fn is_clique(network_map: &NetworkMap, nodes: &[usize]) -> bool {
@@ -54,12 +55,12 @@ fn cliques_of_size(
     network_map: &NetworkMap,
     neighbours: &[HashSet<usize>],
     size: usize,
-) -> impl Iterator<Item=Vec<usize>> {
+) -> impl Iterator<Item = Vec<usize>> {
     fn rec(
         max_size: usize,
         clique: &mut Vec<usize>,
         neighbours: &[HashSet<usize>],
-        f: &mut dyn FnMut(&Vec<usize>) -> (),
+        f: &mut dyn FnMut(&Vec<usize>),
     ) {
         if clique.len() == max_size {
             f(clique);
@@ -77,7 +78,7 @@ fn cliques_of_size(
     let mut clique = Vec::with_capacity(size);
     for i in 0..network_map.nodes.len() {
         clique.push(i);
-        rec(size, &mut clique, &neighbours, &mut |clique| {
+        rec(size, &mut clique, neighbours, &mut |clique| {
             if is_clique(network_map, clique) {
                 result.push(clique.clone());
             }
@@ -96,28 +97,23 @@ fn get_neighbours(network_map: &NetworkMap) -> Vec<HashSet<usize>> {
     m
 }
 
-fn star1(network_map: &NetworkMap) {
+fn star1(network_map: &NetworkMap) -> usize {
     let cliques: HashSet<_> =
-        cliques_of_size(&network_map, &get_neighbours(&network_map), 3).collect();
+        cliques_of_size(network_map, &get_neighbours(network_map), 3).collect();
     let t_cliques = cliques
         .iter()
-        .filter(|nodes| {
-            nodes
-                .iter()
-                .any(|&n| network_map.nodes[n].chars().next().unwrap() == 't')
-        })
-        .map(|nodes| nodes.into_iter().map(|i| &network_map.nodes[*i]))
+        .filter(|nodes| nodes.iter().any(|&n| network_map.nodes[n].starts_with('t')))
+        .map(|nodes| nodes.iter().map(|i| &network_map.nodes[*i]))
         .collect_vec();
-    println!("Star 1: {}", t_cliques.len());
+    t_cliques.len()
 }
 
-fn star2(network_map: &NetworkMap) {
-    let neighbours = get_neighbours(&network_map);
+fn star2(network_map: &NetworkMap) -> String {
+    let neighbours = get_neighbours(network_map);
 
     let mut right = 1;
     while right < network_map.nodes.len() + 1 {
-        println!("{}", right);
-        let has_clique = cliques_of_size(&network_map, &neighbours, right)
+        let has_clique = cliques_of_size(network_map, &neighbours, right)
             .next()
             .is_some();
         if has_clique {
@@ -129,9 +125,8 @@ fn star2(network_map: &NetworkMap) {
 
     let mut left = 0;
     while left + 1 < right {
-        println!("{} {}", left, right);
         let mid = (left + right) / 2;
-        let has_clique = cliques_of_size(&network_map, &neighbours, mid)
+        let has_clique = cliques_of_size(network_map, &neighbours, mid)
             .next()
             .is_some();
         if has_clique {
@@ -140,7 +135,7 @@ fn star2(network_map: &NetworkMap) {
             right = mid;
         }
     }
-    let largest_clique = cliques_of_size(&network_map, &neighbours, left)
+    let largest_clique = cliques_of_size(network_map, &neighbours, left)
         .next()
         .unwrap();
     let largest_clique_names = largest_clique
@@ -148,11 +143,75 @@ fn star2(network_map: &NetworkMap) {
         .map(|i| &network_map.nodes[i])
         .sorted()
         .collect_vec();
-    println!("Star 2: {}", largest_clique_names.into_iter().join(","));
+    largest_clique_names.into_iter().join(",")
+}
+
+impl Solution for Day23 {
+    type Input = NetworkMap;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        star1(input)
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        star2(input)
+    }
 }
 
-pub(crate) fn main() {
-    let network_map = parse_input("day23_input.txt");
-    star1(&network_map);
-    star2(&network_map);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part1(&input), 7);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part2(&input), "co,de,ka,ta");
+    }
 }
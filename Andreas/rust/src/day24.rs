@@ -1,8 +1,11 @@
-#![allow(dead_code)]
-
+use crate::registry::ExportFormat;
+use aoc_common::circuit::{Circuit, CircuitError, Gate, Netlist, NetlistGate, Wire};
+use aoc_common::{split_once, MaybeAnswer, ParseError, Solution};
+use fastrand::Rng;
 use itertools::Itertools;
-use std::collections::HashMap;
-use std::fs;
+use std::fmt::Write;
+
+pub(crate) struct Day24;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 enum LogicOperator {
@@ -11,8 +14,8 @@ enum LogicOperator {
     Xor,
 }
 
-impl LogicOperator {
-    fn apply(&self, lhs: bool, rhs: bool) -> bool {
+impl Gate for LogicOperator {
+    fn apply(self, lhs: bool, rhs: bool) -> bool {
         match self {
             LogicOperator::And => lhs && rhs,
             LogicOperator::Or => lhs || rhs,
@@ -21,134 +24,651 @@ impl LogicOperator {
     }
 }
 
-#[derive(Debug)]
-struct Input {
-    initial_values: HashMap<String, bool>,
-    gates: Vec<(String, LogicOperator, String, String)>,
+impl LogicOperator {
+    fn name(&self) -> &'static str {
+        match self {
+            LogicOperator::And => "AND",
+            LogicOperator::Or => "OR",
+            LogicOperator::Xor => "XOR",
+        }
+    }
+
+    /// Fill colour of the gate in the Graphviz graph.
+    fn colour(&self) -> &'static str {
+        match self {
+            LogicOperator::And => "lightblue",
+            LogicOperator::Or => "palegreen",
+            LogicOperator::Xor => "lightsalmon",
+        }
+    }
 }
 
-fn parse_input(filename: &str) -> Input {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    let (part1, part2) = file_contents.split("\n\n").collect_tuple().unwrap();
+type GateTokens<'a> = (&'a str, LogicOperator, &'a str, &'a str);
+
+/// Points an error of the circuit at the first gate in the input that causes it.
+fn circuit_error(
+    file_contents: &str,
+    initial_values: &[(&str, bool)],
+    gates: &[GateTokens],
+    err: CircuitError,
+) -> ParseError {
+    let drivers = |wire: &str| {
+        gates
+            .iter()
+            .filter(|gate| gate.3 == wire)
+            .map(|gate| gate.3)
+            .collect_vec()
+    };
+    match err {
+        CircuitError::Undriven(wires) => {
+            let read = gates
+                .iter()
+                .flat_map(|gate| [gate.0, gate.2])
+                .find(|&read| read == wires[0])
+                .expect("undriven wires are read by a gate");
+            ParseError::at(file_contents, read, "an input or a wire driven by a gate")
+        }
+        CircuitError::MultipleDrivers(wire) => {
+            // An input is already driven by its initial value.
+            let is_input = initial_values.iter().any(|&(var, _)| var == wire);
+            let driver = drivers(&wire)[usize::from(!is_input)];
+            ParseError::at(file_contents, driver, "a wire without another driver")
+        }
+        CircuitError::Cycle(wires) => ParseError::at(
+            file_contents,
+            drivers(&wires[0])[0],
+            format!("a gate outside of the cycle {}", wires.join(" -> ")),
+        ),
+    }
+}
+
+fn parse_input(file_contents: &str) -> Result<Device, ParseError> {
+    let (part1, part2) = split_once(file_contents, file_contents, "\n\n")?;
     let initial_values = part1
         .lines()
         .map(|line| {
-            let (var, val) = line.split(": ").collect_tuple().unwrap();
-            (var.to_owned(), val == "1")
+            let (var, val) = split_once(file_contents, line, ": ")?;
+            match val {
+                "0" | "1" => Ok((var, val == "1")),
+                _ => Err(ParseError::at(file_contents, val, "'0' or '1'")),
+            }
         })
-        .collect();
+        .collect::<Result<Vec<_>, _>>()?;
     let gates = part2
         .lines()
         .map(|line| {
-            let (lhs, op, rhs, _, res) = line.split(" ").collect_tuple().unwrap();
+            let (lhs, rest) = split_once(file_contents, line, " ")?;
+            let (op, rest) = split_once(file_contents, rest, " ")?;
+            let (rhs, res) = split_once(file_contents, rest, " -> ")?;
             let op = match op {
                 "AND" => LogicOperator::And,
                 "OR" => LogicOperator::Or,
                 "XOR" => LogicOperator::Xor,
-                _ => unreachable!(),
+                _ => return Err(ParseError::at(file_contents, op, "AND, OR or XOR")),
             };
-            (lhs.to_owned(), op, rhs.to_owned(), res.to_owned())
+            Ok((lhs, op, rhs, res))
         })
-        .collect();
-    Input {
-        initial_values,
-        gates,
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut circuit = Circuit::new(
+        initial_values.iter().map(|&(var, _)| var),
+        gates.iter().copied(),
+    )
+    .map_err(|err| circuit_error(file_contents, &initial_values, &gates, err))?;
+    for (var, val) in initial_values {
+        let wire = circuit.wire(var).expect("inputs are wires of the circuit");
+        circuit.set(wire, val);
     }
+    Ok(Device::new(circuit))
 }
 
-#[derive(Debug)]
-struct DeviceLogic {
-    variable_names: Vec<String>,
-    initial_values: HashMap<usize, bool>,
-    gates: Vec<((usize, usize), (LogicOperator, usize))>,
+/// The device as a circuit, with its `x`, `y` and `z` wires ordered by bit. The inputs are set
+/// to their initial values.
+#[derive(Debug, Clone)]
+pub(crate) struct Device {
+    circuit: Circuit<LogicOperator>,
+    x: Vec<Wire>,
+    y: Vec<Wire>,
+    z: Vec<Wire>,
 }
 
-impl From<&Input> for DeviceLogic {
-    fn from(value: &Input) -> Self {
-        let variable_names = value
-            .initial_values
-            .keys()
-            .chain(value.gates.iter().map(|g| &g.3))
-            .unique()
-            .map(|s| s.to_owned())
-            .collect_vec();
-        let initial_values = value
-            .initial_values
-            .iter()
-            .map(|(var, val)| {
-                (
-                    variable_names.iter().position(|x| *x == *var).unwrap(),
-                    *val,
-                )
+impl Device {
+    fn new(circuit: Circuit<LogicOperator>) -> Device {
+        let bits = |prefix| {
+            circuit
+                .wires()
+                .filter(|(_, name)| name.starts_with(prefix))
+                .sorted_by_key(|&(_, name)| name)
+                .map(|(wire, _)| wire)
+                .collect()
+        };
+        Device {
+            x: bits('x'),
+            y: bits('y'),
+            z: bits('z'),
+            circuit,
+        }
+    }
+
+    fn set_number(circuit: &mut Circuit<LogicOperator>, wires: &[Wire], number: u64) {
+        for (bit, &wire) in wires.iter().enumerate() {
+            circuit.set(wire, number >> bit & 1 == 1);
+        }
+    }
+
+    fn number(&self, wires: &[Wire]) -> u64 {
+        wires.iter().rev().fold(0, |number, &wire| {
+            number << 1 | u64::from(self.circuit.value(wire))
+        })
+    }
+
+    /// The `z` number the device computes for the `x` and `y` numbers.
+    fn add(&mut self, x: u64, y: u64) -> u64 {
+        Device::set_number(&mut self.circuit, &self.x, x);
+        Device::set_number(&mut self.circuit, &self.y, y);
+        self.number(&self.z)
+    }
+
+    /// Whether the device adds the `x` and `y` numbers, tried on a carry running through all
+    /// bits and on `rounds` random numbers.
+    fn adds_correctly(&mut self, rng: &mut Rng, rounds: usize) -> bool {
+        let max = (1 << self.x.len()) - 1;
+        std::iter::once((max, 1))
+            .chain((0..rounds).map(|_| (rng.u64(..=max), rng.u64(..=max))))
+            .all(|(x, y)| self.add(x, y) == x + y)
+    }
+}
+
+/// The gates of one bit of a ripple-carry adder, as the wires they drive. Bit `i` adds `xi`,
+/// `yi` and the carry from bit `i - 1`:
+///
+/// - `half_sum = xi XOR yi`, `half_carry = xi AND yi`
+/// - `zi = half_sum XOR carry_in`, `carry_through = half_sum AND carry_in`
+/// - `carry_out = half_carry OR carry_through`
+///
+/// Bit 0 has no incoming carry, so it only consists of the two half gates, and the last carry
+/// is the highest `z` bit. Gates that cannot be found, e.g. because of a swapped wire, are `None`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct AdderBit {
+    half_sum: Option<usize>,
+    half_carry: Option<usize>,
+    sum: Option<usize>,
+    carry_through: Option<usize>,
+    carry_out: Option<usize>,
+}
+
+/// Why the output of a gate does not fit into a ripple-carry adder, see [`AdderBit`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+enum Miswiring {
+    /// A `z` bit that is not driven by an XOR, or the highest one not by an OR.
+    OutputNotSum,
+    /// An XOR of a half sum and a carry that does not drive a `z` bit.
+    SumNotOutput,
+    /// A half sum that does not feed the XOR of its bit.
+    HalfSumUnused,
+    /// An AND that does not feed the OR of a carry.
+    CarryNotCombined,
+}
+
+impl Miswiring {
+    fn description(&self) -> &'static str {
+        match self {
+            Miswiring::OutputNotSum => "drives a z bit without being its sum",
+            Miswiring::SumNotOutput => "adds a half sum and a carry without driving a z bit",
+            Miswiring::HalfSumUnused => "is a half sum that no sum reads",
+            Miswiring::CarryNotCombined => "is a carry that no carry OR reads",
+        }
+    }
+}
+
+impl Device {
+    /// Output of the gate with operator `op` that reads `input`, and `other_input` if given.
+    fn gate_output(
+        &self,
+        op: LogicOperator,
+        input: Wire,
+        other_input: Option<Wire>,
+    ) -> Option<Wire> {
+        self.circuit
+            .gates()
+            .find(|&(lhs, gate_op, rhs, _)| {
+                gate_op == op
+                    && match other_input {
+                        Some(other) => (lhs, rhs) == (input, other) || (rhs, lhs) == (input, other),
+                        None => lhs == input || rhs == input,
+                    }
             })
-            .collect();
-        let gates = value
-            .gates
-            .iter()
-            .map(|(lhs, op, rhs, res)| {
-                (
-                    (
-                        variable_names.iter().position(|x| *x == *lhs).unwrap(),
-                        variable_names.iter().position(|x| *x == *rhs).unwrap(),
-                    ),
-                    (*op, variable_names.iter().position(|x| *x == *res).unwrap()),
-                )
+            .map(|(_, _, _, res)| res)
+    }
+
+    fn adder_bits(&self) -> Vec<AdderBit> {
+        (0..self.x.len())
+            .map(|bit| {
+                let x = self.x.get(bit).copied();
+                let y = self.y.get(bit).copied();
+                let half = |op| self.gate_output(op, x?, Some(y?));
+                let half_sum = half(LogicOperator::Xor);
+                let half_carry = half(LogicOperator::And);
+                let (sum, carry_through) = if bit == 0 {
+                    (half_sum, None)
+                } else {
+                    let full = |op| self.gate_output(op, half_sum?, None);
+                    (full(LogicOperator::Xor), full(LogicOperator::And))
+                };
+                let carry_out = if bit == 0 {
+                    half_carry
+                } else {
+                    half_carry
+                        .or(carry_through)
+                        .and_then(|carry| self.gate_output(LogicOperator::Or, carry, None))
+                };
+                AdderBit {
+                    half_sum,
+                    half_carry,
+                    sum,
+                    carry_through,
+                    carry_out,
+                }
             })
-            .collect();
-        DeviceLogic {
-            variable_names,
-            initial_values,
-            gates,
+            .collect()
+    }
+
+    /// Gate outputs that break the pattern of a ripple-carry adder. A swapped pair of outputs
+    /// usually shows up on both of its wires.
+    fn miswirings(&self) -> Vec<(Wire, Miswiring)> {
+        let last_z = format!("z{:02}", self.x.len());
+        let read_by = |var, op| {
+            self.circuit
+                .gates()
+                .any(|(lhs, gate_op, rhs, _)| gate_op == op && (lhs == var || rhs == var))
+        };
+        let mut miswirings = Vec::new();
+        for (lhs, op, rhs, res) in self.circuit.gates() {
+            let name = self.circuit.name(res);
+            let reads_inputs = self.circuit.is_input(lhs) && self.circuit.is_input(rhs);
+            let first_bit = reads_inputs && self.circuit.name(lhs)[1..] == *"00";
+            let miswiring = if name.starts_with('z') {
+                let expected = if name == last_z {
+                    LogicOperator::Or
+                } else {
+                    LogicOperator::Xor
+                };
+                (op != expected).then_some(Miswiring::OutputNotSum)
+            } else {
+                match op {
+                    LogicOperator::Xor if !reads_inputs || first_bit => {
+                        Some(Miswiring::SumNotOutput)
+                    }
+                    LogicOperator::Xor if !read_by(res, LogicOperator::Xor) => {
+                        Some(Miswiring::HalfSumUnused)
+                    }
+                    LogicOperator::And if !first_bit && !read_by(res, LogicOperator::Or) => {
+                        Some(Miswiring::CarryNotCombined)
+                    }
+                    _ => None,
+                }
+            };
+            miswirings.extend(miswiring.map(|miswiring| (res, miswiring)));
         }
+        miswirings
     }
 }
 
-impl DeviceLogic {
-    fn initial_assignment(&self) -> Vec<Option<bool>> {
-        let mut assignment = vec![None; self.variable_names.len()];
-        for (var, val) in self.initial_values.iter() {
-            assignment[*var] = Some(*val);
+/// Pairs up `wires` into swaps of gate outputs after which the device adds correctly. Swaps
+/// that make the gates form a cycle are skipped, the device is left as it was.
+fn pair_swaps(device: &mut Device, wires: &[Wire], rng: &mut Rng) -> Option<Vec<(Wire, Wire)>> {
+    let Some((&first, rest)) = wires.split_first() else {
+        return device.adds_correctly(rng, 32).then(Vec::new);
+    };
+    (0..rest.len()).find_map(|i| {
+        device.circuit.swap_outputs(first, rest[i]).ok()?;
+        let remaining = [&rest[..i], &rest[i + 1..]].concat();
+        let swaps = pair_swaps(device, &remaining, rng);
+        device
+            .circuit
+            .swap_outputs(first, rest[i])
+            .expect("swapping back restores the acyclic circuit");
+        let mut swaps = swaps?;
+        swaps.push((first, rest[i]));
+        Some(swaps)
+    })
+}
+
+/// The circuit with the wire names of the input, for exporting it.
+fn netlist(device: &Device) -> Netlist<'_> {
+    let circuit = &device.circuit;
+    Netlist {
+        inputs: circuit
+            .wires()
+            .filter(|&(wire, _)| circuit.is_input(wire))
+            .map(|(wire, name)| (name, circuit.value(wire)))
+            .collect(),
+        gates: circuit
+            .gates()
+            .map(|(lhs, op, rhs, res)| NetlistGate {
+                lhs: circuit.name(lhs),
+                operation: op.name(),
+                rhs: circuit.name(rhs),
+                output: circuit.name(res),
+                colour: op.colour(),
+            })
+            .collect(),
+    }
+}
+
+/// The gates found for every bit of the adder, followed by the gate outputs that break its
+/// pattern.
+fn report(device: &Device) -> String {
+    let name = |wire: Option<Wire>| wire.map_or("-", |wire| device.circuit.name(wire));
+    let mut report = String::from("bit  half sum  half carry  sum  carry through  carry out\n");
+    for (bit, gates) in device.adder_bits().iter().enumerate() {
+        writeln!(
+            report,
+            "{bit:3}  {:8}  {:10}  {:3}  {:13}  {}",
+            name(gates.half_sum),
+            name(gates.half_carry),
+            name(gates.sum),
+            name(gates.carry_through),
+            name(gates.carry_out)
+        )
+        .unwrap();
+    }
+    let miswirings = device
+        .miswirings()
+        .into_iter()
+        .map(|(wire, miswiring)| (name(Some(wire)), miswiring))
+        .sorted_by_key(|&(wire, _)| wire)
+        .collect_vec();
+    if miswirings.is_empty() {
+        report.push_str("\nNo gate breaks the adder pattern.\n");
+    } else {
+        report.push_str("\nMiswired gate outputs:\n");
+        for (wire, miswiring) in miswirings {
+            writeln!(report, "{wire} {}", miswiring.description()).unwrap();
         }
-        assignment
     }
+    report
+}
 
-    fn number_from_assignment(&self, assignment: &[Option<bool>]) -> u64 {
-        let z_variable_indices = self
-            .variable_names
-            .iter()
-            .enumerate()
-            .filter(|(_, var)| var.starts_with("z"))
-            .sorted_by_key(|(_, var)| var[1..].parse::<u8>().unwrap())
-            .rev()
-            .map(|(i, _)| i)
-            .collect_vec();
-        let binary = z_variable_indices
-            .iter()
-            .map(|i| if assignment[*i].unwrap() { '1' } else { '0' })
-            .join("");
-        u64::from_str_radix(&binary, 2).unwrap()
+pub(crate) fn export(
+    file_contents: &str,
+    format: ExportFormat,
+) -> Result<Option<String>, ParseError> {
+    let device = parse_input(file_contents)?;
+    Ok(Some(match format {
+        ExportFormat::Dot => netlist(&device).to_dot(),
+        ExportFormat::Text => netlist(&device).to_text(),
+        ExportFormat::Report => report(&device),
+    }))
+}
+
+fn star1(device: &Device) -> u64 {
+    device.number(&device.z)
+}
+
+/// The swapped wires, `None` if no swaps of the miswired gates make the device an adder.
+fn star2(device: &Device) -> Option<String> {
+    let wires = device
+        .miswirings()
+        .into_iter()
+        .map(|(wire, _)| wire)
+        .unique()
+        .collect_vec();
+    // The structural rules only find the wires, re-simulating proves that they are swapped
+    // pairs indeed.
+    let mut rng = Rng::with_seed(24);
+    pair_swaps(&mut device.clone(), &wires, &mut rng)?;
+    Some(
+        wires
+            .into_iter()
+            .map(|wire| device.circuit.name(wire))
+            .sorted()
+            .join(","),
+    )
+}
+
+impl Solution for Day24 {
+    type Input = Device;
+    type Answer1 = u64;
+    type Answer2 = MaybeAnswer<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        star1(input)
+    }
+
+    fn part2(input: &Self::Input) -> MaybeAnswer<String> {
+        star2(input).into()
     }
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day24_input.txt");
-    let device_logic = DeviceLogic::from(&input);
-    println!("{:?}", device_logic);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
 
-    let mut open_gates = device_logic.gates.clone();
-    let mut assignment = device_logic.initial_assignment();
-    while !open_gates.is_empty() {
-        open_gates.retain(|&((lhs, rhs), (op, res))| {
-            if let Some(lhs_val) = assignment[lhs] {
-                if let Some(rhs_val) = assignment[rhs] {
-                    assignment[res] = Some(op.apply(lhs_val, rhs_val));
-                    return false;
+    #[test]
+    fn part1_example() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(Day24::part1(&input), 4);
+    }
+
+    #[test]
+    fn part2_example_is_no_adder() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(Day24::part2(&input).to_string(), "no answer");
+    }
+
+    /// A ripple-carry adder of `bits` bits, with the outputs of the `swapped` wires swapped.
+    fn adder(bits: usize, swapped: &[(&str, &str)]) -> String {
+        let mut input = String::new();
+        for var in ["x", "y"] {
+            for bit in 0..bits {
+                input += &format!("{var}{bit:02}: {}\n", bit % 2);
+            }
+        }
+        input += "\n";
+        let carry = |bit: usize| {
+            if bit + 1 == bits {
+                format!("z{bits:02}")
+            } else {
+                format!("c{bit:02}")
+            }
+        };
+        for bit in 0..bits {
+            let (x, y, z) = (
+                format!("x{bit:02}"),
+                format!("y{bit:02}"),
+                format!("z{bit:02}"),
+            );
+            let mut gates = if bit == 0 {
+                vec![
+                    format!("{x} XOR {y} -> {z}"),
+                    format!("{y} AND {x} -> {}", carry(0)),
+                ]
+            } else {
+                let carry_in = format!("c{:02}", bit - 1);
+                vec![
+                    format!("{x} XOR {y} -> s{bit:02}"),
+                    format!("{x} AND {y} -> a{bit:02}"),
+                    format!("{carry_in} XOR s{bit:02} -> {z}"),
+                    format!("s{bit:02} AND {carry_in} -> t{bit:02}"),
+                    format!("a{bit:02} OR t{bit:02} -> {}", carry(bit)),
+                ]
+            };
+            for gate in &mut gates {
+                for &(a, b) in swapped {
+                    if let Some(gate_inputs) = gate.strip_suffix(&format!("-> {a}")) {
+                        *gate = format!("{gate_inputs}-> {b}");
+                    } else if let Some(gate_inputs) = gate.strip_suffix(&format!("-> {b}")) {
+                        *gate = format!("{gate_inputs}-> {a}");
+                    }
                 }
             }
-            true
-        });
+            input += &gates.join("\n");
+            input += "\n";
+        }
+        input
+    }
+
+    #[test]
+    fn correct_adder_adds() {
+        let mut device = Day24::parse(&adder(6, &[])).unwrap();
+        assert_eq!(device.add(0b101011, 0b111), 0b110010);
+        assert!(device.adds_correctly(&mut Rng::with_seed(1), 100));
+        assert!(device.miswirings().is_empty());
+        let bits = device.adder_bits();
+        assert_eq!(bits.len(), 6);
+        let name = |wire: Option<Wire>| wire.map(|wire| device.circuit.name(wire));
+        assert_eq!(name(bits[0].sum), Some("z00"));
+        assert_eq!(name(bits[0].carry_out), Some("c00"));
+        assert_eq!(name(bits[3].carry_through), Some("t03"));
+        assert_eq!(name(bits[5].carry_out), Some("z06"));
+    }
+
+    #[test]
+    fn finds_swapped_outputs() {
+        let input = Day24::parse(&adder(6, &[("z02", "a02"), ("s04", "a04")])).unwrap();
+        assert!(!input.clone().adds_correctly(&mut Rng::with_seed(1), 100));
+        let miswirings: Vec<_> = input
+            .miswirings()
+            .into_iter()
+            .map(|(wire, miswiring)| (input.circuit.name(wire), miswiring))
+            .sorted_by_key(|&(wire, _)| wire)
+            .collect();
+        assert_eq!(
+            miswirings,
+            [
+                ("a02", Miswiring::SumNotOutput),
+                ("a04", Miswiring::HalfSumUnused),
+                ("s04", Miswiring::CarryNotCombined),
+                ("z02", Miswiring::OutputNotSum),
+            ]
+        );
+        assert_eq!(
+            Day24::part2(&input),
+            MaybeAnswer(Some("a02,a04,s04,z02".to_string()))
+        );
+    }
+
+    #[test]
+    fn half_sums_read_both_inputs() {
+        let adder = adder(3, &[]).replace("x01 XOR y01 -> s01", "x01 XOR c00 -> s01");
+        let device = Day24::parse(&adder).unwrap();
+        let miswirings = device
+            .miswirings()
+            .into_iter()
+            .map(|(wire, miswiring)| (device.circuit.name(wire), miswiring))
+            .collect_vec();
+        assert_eq!(miswirings, [("s01", Miswiring::SumNotOutput)]);
     }
-    println!("{:?}", assignment);
 
-    println!("{}", device_logic.number_from_assignment(&assignment));
+    #[test]
+    fn report_lists_adder_bits_and_miswirings() {
+        let report = export(&adder(3, &[]), ExportFormat::Report)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            report,
+            "bit  half sum  half carry  sum  carry through  carry out\n  \
+               0  z00       c00         z00  -              c00\n  \
+               1  s01       a01         z01  t01            c01\n  \
+               2  s02       a02         z02  t02            z03\n\n\
+             No gate breaks the adder pattern.\n"
+        );
+        let report = export(&adder(3, &[("z01", "a01")]), ExportFormat::Report)
+            .unwrap()
+            .unwrap();
+        assert!(report.contains("  1  s01       z01         a01  t01            -\n"));
+        assert!(report.ends_with(
+            "Miswired gate outputs:\n\
+             a01 adds a half sum and a carry without driving a z bit\n\
+             z01 drives a z bit without being its sum\n"
+        ));
+    }
+
+    #[test]
+    fn swaps_into_a_cycle_are_reported() {
+        let err = Day24::parse(&adder(3, &[("c00", "z02")])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:13:16: expected a gate outside of the cycle t01 -> c01 -> c00, found \"t01\""
+        );
+        let mut device = Day24::parse(&adder(3, &[])).unwrap();
+        let [c00, z02] = ["c00", "z02"].map(|name| device.circuit.wire(name).unwrap());
+        assert!(device.circuit.swap_outputs(c00, z02).is_err());
+        assert_eq!(device.add(3, 1), 4);
+    }
+
+    #[test]
+    fn circuit_errors_point_at_the_gate() {
+        let error = |from, to| {
+            Day24::parse(&EXAMPLE.replace(from, to))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("XOR y01", "XOR w01"),
+            "<input>:9:9: expected an input or a wire driven by a gate, found \"w01\""
+        );
+        assert_eq!(
+            error("-> z02", "-> z01"),
+            "<input>:10:15: expected a wire without another driver, found \"z01\""
+        );
+        assert_eq!(
+            error("-> z02", "-> y02"),
+            "<input>:10:15: expected a wire without another driver, found \"y02\""
+        );
+    }
+
+    #[test]
+    fn netlist_is_normalized() {
+        let netlist = export(EXAMPLE, ExportFormat::Text).unwrap().unwrap();
+        let expected = "x00: 1\nx01: 1\nx02: 1\ny00: 0\ny01: 1\ny02: 0\n\n\
+            x00 AND y00 -> z00\nx01 XOR y01 -> z01\nx02 OR y02 -> z02\n";
+        assert_eq!(netlist, expected);
+        let reordered = "y00: 0\nx00: 1\n\ny00 XOR x00 -> z00\nx00 AND y00 -> c00\n";
+        assert_eq!(
+            export(reordered, ExportFormat::Text).unwrap().unwrap(),
+            "x00: 1\ny00: 0\n\nx00 AND y00 -> c00\nx00 XOR y00 -> z00\n"
+        );
+        // The netlist is a valid input for the same circuit.
+        let adder = adder(6, &[("z02", "a02"), ("s04", "a04")]);
+        let netlist = export(&adder, ExportFormat::Text).unwrap().unwrap();
+        assert_eq!(
+            export(&netlist, ExportFormat::Text).unwrap().unwrap(),
+            netlist
+        );
+        let original = Day24::parse(&adder).unwrap();
+        let reparsed = Day24::parse(&netlist).unwrap();
+        assert_eq!(Day24::part2(&reparsed), Day24::part2(&original));
+    }
+
+    #[test]
+    fn dot_ranks_wires_by_bit() {
+        let dot = export(EXAMPLE, ExportFormat::Dot).unwrap().unwrap();
+        assert!(dot.starts_with("digraph circuit {\n"));
+        assert!(dot.contains(
+            "{ rank=source; edge [style=invis]; \"x00\" -> \"y00\" -> \"x01\" -> \"y01\" -> \
+             \"x02\" -> \"y02\"; }"
+        ));
+        assert!(dot.contains("{ rank=sink; edge [style=invis]; \"z00\" -> \"z01\" -> \"z02\"; }"));
+        assert!(dot.contains("g1 [label=\"XOR\", shape=box, style=filled, fillcolor=lightsalmon];"));
+        assert!(dot.contains("\"x01\" -> g1; \"y01\" -> g1; g1 -> \"z01\";"));
+        assert!(dot.ends_with("}\n"));
+    }
 }
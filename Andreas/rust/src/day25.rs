@@ -1,8 +1,11 @@
 #![allow(dead_code)]
 
+use crate::grid::Grid;
+use aoc_common::{parse_chars, ParseError, Solution, Unsolved};
 use itertools::Itertools;
 use std::cmp::PartialEq;
-use std::fs;
+
+pub(crate) struct Day25;
 
 #[derive(Debug, PartialEq, Eq)]
 enum SchemaType {
@@ -11,46 +14,61 @@ enum SchemaType {
 }
 
 #[derive(Debug)]
-struct Schema {
+pub(crate) struct Schema {
     typ: SchemaType,
     columns: Vec<usize>,
     height: usize,
 }
 
-fn parse_input(filename: &str) -> Vec<Schema> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+fn parse_input(file_contents: &str) -> Result<Vec<Schema>, ParseError> {
     file_contents
         .split("\n\n")
         .map(|schema_string| {
-            let width = schema_string.lines().next().unwrap().len();
+            let first_line = schema_string
+                .lines()
+                .next()
+                .ok_or_else(|| ParseError::at(file_contents, schema_string, "a schema"))?;
+            let width = first_line.len();
             let height = schema_string.lines().count();
-            let typ = if schema_string.lines().next().unwrap().contains('.') {
+            let typ = if first_line.contains('.') {
                 SchemaType::Key
             } else {
                 SchemaType::Lock
             };
-            let mut columns = vec![0; width];
-            for (y, line) in schema_string.lines().enumerate() {
-                for (x, c) in line.chars().enumerate() {
-                    if typ == SchemaType::Lock && c == '.' && columns[x] == 0 {
-                        columns[x] = y;
-                    } else if typ == SchemaType::Key && c == '#' && columns[x] == 0 {
-                        columns[x] = height - y;
-                    }
+            let mut pins = Vec::new();
+            for line in schema_string.lines() {
+                if line.len() != width {
+                    return Err(ParseError::at(
+                        file_contents,
+                        line,
+                        format!("a row of {width} pins"),
+                    ));
                 }
+                pins.extend(parse_chars(file_contents, line, "'#' or '.'", |c| {
+                    matches!(c, '#' | '.').then_some(c)
+                })?);
             }
-            Schema {
+            let schema = Grid {
+                width: width as u32,
+                height: height as u32,
+                entities: pins,
+            };
+            // Locks hang down from the top row and keys stand up from the bottom row, so the
+            // height of a pin is the number of '#' in its column either way.
+            let columns = schema
+                .columns()
+                .map(|column| column.filter(|&&c| c == '#').count())
+                .collect();
+            Ok(Schema {
                 typ,
                 columns,
                 height,
-            }
+            })
         })
         .collect()
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day25_input.txt");
-
+fn star1(input: &[Schema]) -> usize {
     let mut sum = 0;
     for (key, lock) in input.iter().cartesian_product(input.iter()) {
         if key.typ != SchemaType::Key || lock.typ != SchemaType::Lock {
@@ -65,5 +83,76 @@ pub(crate) fn main() {
             sum += 1;
         }
     }
-    println!("{}", sum);
+    sum
+}
+
+impl Solution for Day25 {
+    type Input = Vec<Schema>;
+    type Answer1 = usize;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        star1(input)
+    }
+
+    fn part2(_input: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day25::parse(EXAMPLE).unwrap();
+        assert_eq!(Day25::part1(&input), 3);
+    }
 }
@@ -1,10 +1,13 @@
 #![allow(dead_code)]
 
+use aoc_common::{ParseError, Solution};
 use regex::Regex;
-use std::fs;
 use std::sync::LazyLock;
 
-static EVAL_MUL_RE: LazyLock<Regex> = LazyLock::new(|| {Regex::new(r"^mul\((\d+),(\d+)\)$").unwrap()});
+pub(crate) struct Day3;
+
+static EVAL_MUL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^mul\((\d+),(\d+)\)$").unwrap());
 
 fn eval_mul(s: &str) -> u32 {
     let capture = EVAL_MUL_RE.captures(s).unwrap();
@@ -13,37 +16,72 @@ fn eval_mul(s: &str) -> u32 {
     a * b
 }
 
-pub(crate) fn main() {
-    let input = fs::read_to_string("day3_input.txt").unwrap();
-
-    {
-        // star 1
-        let re = Regex::new(r"mul\(\d+,\d+\)").unwrap();
-        let sum: u32 = re
-            .captures_iter(&input)
-            .map(|c| eval_mul(c.get(0).unwrap().as_str()))
-            .sum();
-        println!("Star 1: {}", sum);
-    }
+fn star1(input: &str) -> u32 {
+    let re = Regex::new(r"mul\(\d+,\d+\)").unwrap();
+    re.captures_iter(input)
+        .map(|c| eval_mul(c.get(0).unwrap().as_str()))
+        .sum()
+}
 
-    {
-        // star 2
-        let re = Regex::new(r"(mul\(\d+,\d+\))|(do\(\))|(don't\(\))").unwrap();
-        let mut sum = 0;
-        let mut enabled = true;
-        for capture in re.captures_iter(&input) {
-            if let Some(m) = capture.get(1) {
-                if enabled {
-                    sum += eval_mul(m.as_str());
-                }
-            } else if let Some(_) = capture.get(2) {
-                enabled = true;
-            } else if let Some(_) = capture.get(3) {
-                enabled = false;
-            } else {
-                unreachable!();
+fn star2(input: &str) -> u32 {
+    let re = Regex::new(r"(mul\(\d+,\d+\))|(do\(\))|(don't\(\))").unwrap();
+    let mut sum = 0;
+    let mut enabled = true;
+    for capture in re.captures_iter(input) {
+        if let Some(m) = capture.get(1) {
+            if enabled {
+                sum += eval_mul(m.as_str());
             }
+        } else if capture.get(2).is_some() {
+            enabled = true;
+        } else if capture.get(3).is_some() {
+            enabled = false;
+        } else {
+            unreachable!();
         }
-        println!("Star 2: {}", sum);
+    }
+    sum
+}
+
+impl Solution for Day3 {
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        star1(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        star2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const EXAMPLE2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day3::part1(&input), 161);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day3::part2(&input), 48);
     }
 }
@@ -1,43 +1,43 @@
 #![allow(dead_code)]
 
+use aoc_common::{parse_number, split_once, ParseError, Solution};
 use std::collections::{HashMap, HashSet};
-use std::fs;
 
-#[derive(Debug)]
-struct Input {
+pub(crate) struct Day5;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Input {
     rules: HashSet<(usize, usize)>,
     pages: Vec<Vec<usize>>,
 }
 
-fn parse_input(filename: &str) -> Input {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    let file_parts: Vec<_> = file_contents.split("\n\n").collect();
-    assert_eq!(file_parts.len(), 2);
-    let rules_raw = file_parts[0];
-    let pages_raw = file_parts[1];
+fn parse_input(file_contents: &str) -> Result<Input, ParseError> {
+    let (rules_raw, pages_raw) = split_once(file_contents, file_contents, "\n\n")?;
     let rules = rules_raw
         .lines()
         .map(|line| {
-            let split_l: [usize; 2] = line
-                .split("|")
-                .map(|x| x.parse().unwrap())
-                .collect::<Vec<_>>()
-                .try_into()
-                .unwrap();
-            split_l.try_into().unwrap()
+            let (a, b) = split_once(file_contents, line, "|")?;
+            Ok((
+                parse_number(file_contents, a)?,
+                parse_number(file_contents, b)?,
+            ))
         })
-        .collect();
+        .collect::<Result<_, _>>()?;
     let pages = pages_raw
         .lines()
-        .map(|line| line.split(",").map(|x| x.parse().unwrap()).collect())
-        .collect();
-    Input { rules, pages }
+        .map(|line| {
+            line.split(",")
+                .map(|x| parse_number(file_contents, x))
+                .collect()
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Input { rules, pages })
 }
 
 fn find_broken_rule_in_page_split(
     lhs: &HashMap<usize, i32>,
     rhs: &HashMap<usize, i32>,
-    rules: &HashSet<(usize, usize)>
+    rules: &HashSet<(usize, usize)>,
 ) -> Option<(usize, usize)> {
     for rule in rules {
         if *rhs.get(&rule.0).unwrap_or(&0) > 0 && *lhs.get(&rule.1).unwrap_or(&0) > 0 {
@@ -47,7 +47,10 @@ fn find_broken_rule_in_page_split(
     None
 }
 
-fn find_broken_rule_in_page(page: &[usize], rules: &HashSet<(usize, usize)>) -> Option<(usize, usize)> {
+fn find_broken_rule_in_page(
+    page: &[usize],
+    rules: &HashSet<(usize, usize)>,
+) -> Option<(usize, usize)> {
     let mut lhs = HashMap::new();
     let mut rhs = {
         let mut rhs = HashMap::new();
@@ -73,9 +76,7 @@ fn find_broken_rule_in_page(page: &[usize], rules: &HashSet<(usize, usize)>) ->
     None
 }
 
-fn star1() {
-    let input = parse_input("day5_input.txt");
-
+fn star1(input: &Input) -> usize {
     let mut midsum = 0;
     for page in input.pages.iter() {
         if find_broken_rule_in_page(page, &input.rules).is_none() {
@@ -83,13 +84,10 @@ fn star1() {
         }
     }
 
-    println!("Star 1: {}", midsum);
+    midsum
 }
 
-fn fix_broken_page(
-    page: &mut [usize],
-    rules: &HashSet<(usize, usize)>,
-) {
+fn fix_broken_page(page: &mut [usize], rules: &HashSet<(usize, usize)>) {
     // i hate this
     while let Some(rule) = find_broken_rule_in_page(page, rules) {
         let i = page.iter().position(|x| *x == rule.0).unwrap();
@@ -98,8 +96,8 @@ fn fix_broken_page(
     }
 }
 
-fn star2() {
-    let mut input = parse_input("day5_input.txt");
+fn star2(input: &Input) -> usize {
+    let mut input = input.clone();
 
     let mut midsum = 0;
     for page in input.pages.iter_mut() {
@@ -110,10 +108,71 @@ fn star2() {
         midsum += page[page.len() / 2];
     }
 
-    println!("Star 2: {}", midsum);
+    midsum
 }
 
-pub(crate) fn main() {
-    star1();
-    star2();
+impl Solution for Day5 {
+    type Input = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        star1(input)
+    }
+
+    fn part2(input: &Self::Input) -> usize {
+        star2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), 123);
+    }
 }
@@ -1,8 +1,12 @@
 #![allow(dead_code)]
 
+use crate::playback::{Animated, Simulation};
+use aoc_common::render::{Cell, Frame, Rgb};
+use aoc_common::{parse_chars, ParseError, Solution};
 use itertools::Itertools;
 use std::collections::HashSet;
-use std::fs;
+
+pub(crate) struct Day6;
 
 #[derive(Debug, Eq, Hash, PartialEq, Copy, Clone)]
 struct Pos(i32, i32);
@@ -18,8 +22,8 @@ impl Pos {
     }
 }
 
-#[derive(Debug)]
-struct MapBase {
+#[derive(Debug, Clone)]
+pub(crate) struct MapBase {
     width: i32,
     height: i32,
     blockades: HashSet<Pos>,
@@ -55,7 +59,7 @@ impl Direction {
 }
 
 #[derive(Debug, Clone)]
-struct MapState {
+pub(crate) struct MapState {
     guard_position: Pos,
     guard_direction: Direction,
     visited: HashSet<(Pos, Direction)>,
@@ -74,60 +78,65 @@ impl MapState {
     }
 }
 
-fn parse_input(filename: &str) -> (MapBase, MapState) {
-    let file_contents = fs::read_to_string(filename).unwrap();
+fn parse_input(file_contents: &str) -> Result<(MapBase, MapState), ParseError> {
     let mut base = MapBase {
-        width: file_contents.lines().next().unwrap().chars().count() as i32,
+        width: file_contents
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::at(file_contents, "", "a map"))?
+            .chars()
+            .count() as i32,
         height: file_contents.lines().count() as i32,
         blockades: HashSet::new(),
     };
     let mut guard_position = None;
-    let mut guard_direction = None;
-    for (i, c) in file_contents.chars().filter(|c| *c != '\n').enumerate() {
-        let pos = Pos(i as i32 % base.width, i as i32 / base.height);
-        match c {
-            '.' => {}
-            '#' => {
-                base.blockades.insert(pos);
-            }
-            '^' => {
-                guard_position = Some(pos);
-                guard_direction = Some(Direction::Up);
-            }
-            _ => {
-                unreachable!();
+    for (y, line) in file_contents.lines().enumerate() {
+        if line.chars().count() as i32 != base.width {
+            return Err(ParseError::at(
+                file_contents,
+                line,
+                format!("a row of {} tiles", base.width),
+            ));
+        }
+        let tiles = parse_chars(file_contents, line, "'.', '#' or '^'", |c| {
+            matches!(c, '.' | '#' | '^').then_some(c)
+        })?;
+        for (x, c) in tiles.into_iter().enumerate() {
+            let pos = Pos(x as i32, y as i32);
+            match c {
+                '#' => {
+                    base.blockades.insert(pos);
+                }
+                '^' => {
+                    guard_position = Some(pos);
+                }
+                _ => {}
             }
         }
     }
-    (
+    let guard_position =
+        guard_position.ok_or_else(|| ParseError::at(file_contents, "", "a guard '^'"))?;
+    Ok((
         base,
         MapState {
-            guard_position: guard_position.unwrap(),
-            guard_direction: guard_direction.unwrap(),
+            guard_position,
+            guard_direction: Direction::Up,
             visited: HashSet::new(),
         },
-    )
+    ))
 }
 
-fn star1() {
-    let (base, mut state) = parse_input("day6_input.txt");
+fn star1(base: &MapBase, state: &MapState) -> usize {
+    let mut state = state.clone();
     while !base.is_out_of_map(state.guard_position) {
-        state.step(&base);
+        state.step(base);
     }
-    println!(
-        "Star 1: {}",
-        state
-            .visited
-            .into_iter()
-            .map(|(p, _)| { p })
-            .unique()
-            .count()
-    );
+    state.visited.into_iter().map(|(p, _)| p).unique().count()
 }
 
 fn is_loop(base: &MapBase, mut state: MapState) -> bool {
     loop {
-        state.step(&base);
+        state.step(base);
         if base.is_out_of_map(state.guard_position) {
             return false;
         }
@@ -145,16 +154,16 @@ fn block_causes_loop(map_base: &mut MapBase, map_state: MapState, pos: Pos) -> b
     if is_new_block {
         map_base.blockades.insert(pos);
     }
-    let result = is_loop(&map_base, map_state);
+    let result = is_loop(map_base, map_state);
     if is_new_block {
         map_base.blockades.remove(&pos);
     }
     result
 }
 
-fn star2() {
+fn star2(base: &MapBase, state: &MapState) -> usize {
     // build in release mode, or this might take a while
-    let (mut base, state) = parse_input("day6_input.txt");
+    let mut base = base.clone();
     let mut loop_positions = 0;
     for x in 0..base.width {
         for y in 0..base.height {
@@ -163,11 +172,144 @@ fn star2() {
             }
         }
     }
+    loop_positions
+}
+
+/// The guard's walk, ending when the guard leaves the map or walks in a loop.
+#[derive(Debug, Clone)]
+pub(crate) struct GuardWalk {
+    base: MapBase,
+    state: MapState,
+}
+
+impl GuardWalk {
+    fn is_loop(&self) -> bool {
+        self.state
+            .visited
+            .contains(&(self.state.guard_position, self.state.guard_direction))
+    }
+}
+
+impl Simulation for GuardWalk {
+    fn step(&mut self) -> bool {
+        if self.base.is_out_of_map(self.state.guard_position) || self.is_loop() {
+            return false;
+        }
+        self.state.step(&self.base);
+        true
+    }
+
+    fn render(&self) -> Frame {
+        let guard = match self.state.guard_direction {
+            Direction::Left => '<',
+            Direction::Right => '>',
+            Direction::Up => '^',
+            Direction::Down => 'v',
+        };
+        let mut frame = Frame::new(
+            self.base.width as usize,
+            self.base.height as usize,
+            |x, y| {
+                let pos = Pos(x as i32, y as i32);
+                if pos == self.state.guard_position {
+                    Cell::coloured(guard, Rgb::RED)
+                } else if self.base.is_blocked(pos) {
+                    Cell::coloured('#', Rgb::GREY)
+                } else {
+                    Cell::new('.')
+                }
+            },
+        );
+        frame.overlay(
+            self.state
+                .visited
+                .iter()
+                .map(|&(Pos(x, y), _)| (x as usize, y as usize)),
+            Rgb::YELLOW,
+        );
+        frame
+    }
+
+    fn status(&self) -> String {
+        let visited = self.state.visited.iter().map(|(p, _)| p).unique().count();
+        let looping = if self.is_loop() {
+            ", walking in a loop"
+        } else {
+            ""
+        };
+        format!("{visited} tiles visited{looping}")
+    }
+}
+
+impl Solution for Day6 {
+    type Input = (MapBase, MapState);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
 
-    println!("Star 2: {}", loop_positions);
+    fn part1((base, state): &Self::Input) -> usize {
+        star1(base, state)
+    }
+
+    fn part2((base, state): &Self::Input) -> usize {
+        star2(base, state)
+    }
 }
 
-pub(crate) fn main() {
-    star1();
-    star2();
+impl Animated for Day6 {
+    type Simulation = GuardWalk;
+
+    fn simulation((base, state): &Self::Input, _part: u8) -> GuardWalk {
+        GuardWalk {
+            base: base.clone(),
+            state: state.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), 41);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), 6);
+    }
+
+    #[test]
+    fn playback_detects_loop() {
+        let (mut base, mut state) = Day6::parse(EXAMPLE).unwrap();
+        base.blockades.insert(Pos(3, 6));
+        state.visited.clear();
+        let mut walk = GuardWalk { base, state };
+        let mut steps = 0;
+        while walk.step() {
+            steps += 1;
+        }
+        assert!(steps < 100);
+        assert!(walk.status().ends_with("walking in a loop"));
+    }
 }
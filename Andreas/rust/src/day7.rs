@@ -1,10 +1,12 @@
 #![allow(dead_code)]
 
+use aoc_common::{parse_number, split_once, ParseError, Solution};
 use gat_lending_iterator::LendingIterator;
-use std::fs;
+
+pub(crate) struct Day7;
 
 #[derive(Debug)]
-struct Equation {
+pub(crate) struct Equation {
     sum: i64,
     operands: Vec<i64>,
 }
@@ -29,19 +31,17 @@ impl Equation {
     }
 }
 
-fn parse_input(filename: &str) -> Vec<Equation> {
-    let file_contents = fs::read_to_string(filename).unwrap();
+fn parse_input(file_contents: &str) -> Result<Vec<Equation>, ParseError> {
     file_contents
         .lines()
         .map(|line| {
-            let equation_sides: [&str; 2] =
-                line.split(": ").collect::<Vec<_>>().try_into().unwrap();
-            let sum = equation_sides[0].parse().unwrap();
-            let operands = equation_sides[1]
+            let (sum, operands) = split_once(file_contents, line, ": ")?;
+            let sum = parse_number(file_contents, sum)?;
+            let operands = operands
                 .split(" ")
-                .map(|op| op.parse().unwrap())
-                .collect();
-            Equation { sum, operands }
+                .map(|op| parse_number(file_contents, op))
+                .collect::<Result<_, _>>()?;
+            Ok(Equation { sum, operands })
         })
         .collect()
 }
@@ -91,8 +91,8 @@ impl LendingIterator for OperationsIter {
                             exhausted = false;
                             break;
                         } else {
-                            Operation::Addition
-                        };
+                            *op = Operation::Addition;
+                        }
                     }
                     Operation::Concatenation => {
                         *op = Operation::Addition;
@@ -129,18 +129,53 @@ fn sum_valid_equations(input: &[Equation], make_iter: &dyn Fn(&Equation) -> Oper
         .sum()
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day7_input.txt");
-    println!(
-        "Star 1: {}",
-        sum_valid_equations(&input, &|eq| {
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        sum_valid_equations(input, &|eq| {
             OperationsIter::new(eq.operands.len() - 1, false)
         })
-    );
-    println!(
-        "Star 2: {}",
-        sum_valid_equations(&input, &|eq| {
+    }
+
+    fn part2(input: &Self::Input) -> i64 {
+        sum_valid_equations(input, &|eq| {
             OperationsIter::new(eq.operands.len() - 1, true)
         })
-    );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), 11387);
+    }
 }
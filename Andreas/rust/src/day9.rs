@@ -1,14 +1,14 @@
 #![allow(dead_code)]
 
+use aoc_common::{parse_chars, ParseError, Solution};
 use itertools::{repeat_n, Itertools};
-use std::fs;
 
-fn parse_input(filename: &str) -> Vec<u8> {
-    let file_contents = fs::read_to_string(filename).unwrap();
-    file_contents
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect()
+pub(crate) struct Day9;
+
+fn parse_input(file_contents: &str) -> Result<Vec<u8>, ParseError> {
+    parse_chars(file_contents, file_contents.trim_end(), "a digit", |c| {
+        c.to_digit(10).map(|d| d as u8)
+    })
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -34,7 +34,7 @@ fn pretty_print_blocks(blocks: &[Block]) -> String {
 
 fn expand_fs(block_sizes: &[u8]) -> Vec<Block> {
     #![allow(unstable_name_collisions)]
-    let block_ids = (0..).map(|i| Block::File(i)).intersperse(Block::Empty);
+    let block_ids = (0..).map(Block::File).intersperse(Block::Empty);
     block_ids
         .zip(block_sizes)
         .flat_map(|(block, &size)| repeat_n(block, size as usize))
@@ -85,8 +85,7 @@ fn defragment_by_file(blocks: &mut [Block]) {
         let mut first_empty_start = 1;
         let mut first_empty_end = 1;
         while first_empty_end < blocks.len() {
-            if first_empty_end - first_empty_start >= last_file_end - last_file_start
-            {
+            if first_empty_end - first_empty_start >= last_file_end - last_file_start {
                 break;
             }
             first_empty_end += 1;
@@ -95,7 +94,9 @@ fn defragment_by_file(blocks: &mut [Block]) {
             }
         }
 
-        if first_empty_end - first_empty_start >= last_file_end - last_file_start && first_empty_start < last_file_start {
+        if first_empty_end - first_empty_start >= last_file_end - last_file_start
+            && first_empty_start < last_file_start
+        {
             for i in 0..(last_file_end - last_file_start) {
                 blocks.swap(first_empty_start + i, last_file_start + i);
             }
@@ -123,20 +124,55 @@ fn checksum(blocks: &[Block]) -> usize {
         .sum()
 }
 
-fn star1(input: &[u8]) {
-    let mut blocks = expand_fs(&input);
-    defragment_by_block(&mut blocks);
-    println!("Star 1: {}", checksum(&blocks));
-}
+impl Solution for Day9 {
+    type Input = Vec<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> usize {
+        let mut blocks = expand_fs(input);
+        defragment_by_block(&mut blocks);
+        checksum(&blocks)
+    }
 
-fn star2(input: &[u8]) {
-    let mut blocks = expand_fs(&input);
-    defragment_by_file(&mut blocks);
-    println!("Star 2: {}", checksum(&blocks));
+    fn part2(input: &Self::Input) -> usize {
+        let mut blocks = expand_fs(input);
+        defragment_by_file(&mut blocks);
+        checksum(&blocks)
+    }
 }
 
-pub(crate) fn main() {
-    let input = parse_input("day9_input.txt");
-    star1(&input);
-    star2(&input);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    const SMALL_EXAMPLE: &str = "\
+12345
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), 1928);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), 2858);
+    }
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day9::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), 60);
+    }
 }
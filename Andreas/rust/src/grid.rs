@@ -1,4 +1,11 @@
+#![allow(dead_code)]
+
+use aoc_common::region::Regions;
+use aoc_common::render::{Cell, Frame};
+use aoc_common::{parse_grid, ParseError};
 use itertools::Itertools;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Neg, Sub};
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
 pub struct Position {
@@ -7,32 +14,159 @@ pub struct Position {
 }
 
 impl Position {
+    pub fn new(x: u32, y: u32) -> Position {
+        Position { x, y }
+    }
+
+    /// Moves one step into `direction`.
+    ///
+    /// Panics when stepping left of column 0 or above row 0, use [`Grid::try_move`] for
+    /// positions that might leave the grid.
     pub fn move_to(&self, direction: Direction) -> Position {
-        self.move_by(direction, 1)
+        self.checked_add(direction.offset())
+            .expect("moved to a negative coordinate")
     }
 
-    pub fn move_by(&self, direction: Direction, distance: u32) -> Position {
-        match direction {
-            Direction::Up => Position {
-                x: self.x,
-                y: self.y.wrapping_sub(distance),
-            },
-            Direction::Down => Position {
-                x: self.x,
-                y: self.y.wrapping_add(distance),
-            },
-            Direction::Left => Position {
-                x: self.x.wrapping_sub(distance),
-                y: self.y,
-            },
-            Direction::Right => Position {
-                x: self.x.wrapping_add(distance),
-                y: self.y,
-            },
+    /// Position `offset` away, `None` if a coordinate would become negative.
+    pub fn checked_add(&self, offset: Offset) -> Option<Position> {
+        Some(Position {
+            x: self.x.checked_add_signed(offset.dx)?,
+            y: self.y.checked_add_signed(offset.dy)?,
+        })
+    }
+}
+
+impl Sub for Position {
+    type Output = Offset;
+
+    fn sub(self, other: Position) -> Offset {
+        Offset {
+            dx: self.x as i32 - other.x as i32,
+            dy: self.y as i32 - other.y as i32,
         }
     }
 }
 
+impl From<Position> for (usize, usize) {
+    fn from(position: Position) -> (usize, usize) {
+        (position.x as usize, position.y as usize)
+    }
+}
+
+/// Signed distance between two positions, e.g. a single step into a [`Direction`].
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct Offset {
+    pub dx: i32,
+    pub dy: i32,
+}
+
+impl Offset {
+    pub const fn new(dx: i32, dy: i32) -> Offset {
+        Offset { dx, dy }
+    }
+}
+
+impl Add for Offset {
+    type Output = Offset;
+
+    fn add(self, other: Offset) -> Offset {
+        Offset::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Mul<i32> for Offset {
+    type Output = Offset;
+
+    fn mul(self, factor: i32) -> Offset {
+        Offset::new(self.dx * factor, self.dy * factor)
+    }
+}
+
+impl Neg for Offset {
+    type Output = Offset;
+
+    fn neg(self) -> Offset {
+        Offset::new(-self.dx, -self.dy)
+    }
+}
+
+/// Position with signed coordinates, for grids without a top left corner, see
+/// [`SparseGrid`](crate::sparse_grid::SparseGrid).
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Ord, PartialOrd)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+}
+
+impl Add<Offset> for Point {
+    type Output = Point;
+
+    fn add(self, offset: Offset) -> Point {
+        Point::new(self.x + offset.dx, self.y + offset.dy)
+    }
+}
+
+impl Sub for Point {
+    type Output = Offset;
+
+    fn sub(self, other: Point) -> Offset {
+        Offset::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Point {
+        Point::new(position.x as i32, position.y as i32)
+    }
+}
+
+/// The topology of a `width` x `height` grid whose edges wrap around: leaving the grid on one
+/// side enters it again on the opposite side.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct Torus {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Torus {
+    /// Panics if the grid is empty, there is nothing to wrap around to then.
+    pub const fn new(width: u32, height: u32) -> Torus {
+        assert!(
+            width > 0 && height > 0,
+            "a torus needs a width and height of at least 1"
+        );
+        Torus { width, height }
+    }
+
+    /// The position inside the grid that `point` wraps around to.
+    pub fn wrap(&self, point: Point) -> Position {
+        Position::new(
+            point.x.rem_euclid(self.width as i32) as u32,
+            point.y.rem_euclid(self.height as i32) as u32,
+        )
+    }
+
+    /// Position `offset` away, wrapping around the edges as often as needed.
+    pub fn offset(&self, position: Position, offset: Offset) -> Position {
+        let offset = Offset::new(
+            offset.dx.rem_euclid(self.width as i32),
+            offset.dy.rem_euclid(self.height as i32),
+        );
+        self.wrap(Point::from(position) + offset)
+    }
+
+    /// Position one step into `direction`, wrapping around the edges.
+    pub fn move_to(&self, position: Position, direction: Direction) -> Position {
+        self.offset(position, direction.offset())
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
 pub enum Direction {
     Up,
@@ -51,6 +185,15 @@ impl Direction {
         ]
     }
 
+    pub fn offset(&self) -> Offset {
+        match self {
+            Direction::Up => Offset::new(0, -1),
+            Direction::Down => Offset::new(0, 1),
+            Direction::Left => Offset::new(-1, 0),
+            Direction::Right => Offset::new(1, 0),
+        }
+    }
+
     pub fn turn90(&self) -> Direction {
         match self {
             Direction::Up => Direction::Right,
@@ -79,14 +222,16 @@ impl Direction {
     }
 }
 
-impl From<char> for Direction {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '^' => Direction::Up,
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            'v' => Direction::Down,
-            _ => unreachable!(),
+            '^' => Ok(Direction::Up),
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            _ => Err(value),
         }
     }
 }
@@ -102,6 +247,79 @@ impl From<Direction> for char {
     }
 }
 
+impl From<Direction> for Offset {
+    fn from(direction: Direction) -> Offset {
+        direction.offset()
+    }
+}
+
+/// The four orthogonal and the four diagonal directions, clockwise starting with `Up`.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub const DIAGONALS: [Direction8; 4] = [
+        Direction8::UpRight,
+        Direction8::DownRight,
+        Direction8::DownLeft,
+        Direction8::UpLeft,
+    ];
+
+    pub fn offset(&self) -> Offset {
+        match self {
+            Direction8::Up => Offset::new(0, -1),
+            Direction8::UpRight => Offset::new(1, -1),
+            Direction8::Right => Offset::new(1, 0),
+            Direction8::DownRight => Offset::new(1, 1),
+            Direction8::Down => Offset::new(0, 1),
+            Direction8::DownLeft => Offset::new(-1, 1),
+            Direction8::Left => Offset::new(-1, 0),
+            Direction8::UpLeft => Offset::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        Direction8::DIAGONALS.contains(self)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        match direction {
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+        }
+    }
+}
+
+impl From<Direction8> for Offset {
+    fn from(direction: Direction8) -> Offset {
+        direction.offset()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
     pub width: u32,
@@ -110,41 +328,94 @@ pub struct Grid<T> {
 }
 
 impl<T: PartialEq> Grid<T> {
-    pub fn parse_from_string<F>(string: &str, mapper: F) -> Result<Grid<T>, ()>
+    /// Parses a rectangular grid with one entity per character. The mapper returns `None` for
+    /// characters that are not `expected`.
+    pub fn parse_from_string<F>(
+        string: &str,
+        expected: &str,
+        mapper: F,
+    ) -> Result<Grid<T>, ParseError>
     where
-        F: Fn(char) -> T,
+        F: Fn(char) -> Option<T>,
     {
-        let width = string.lines().next().ok_or(())?.chars().count() as u32;
-        let height = string.lines().count() as u32;
-        let entities: Vec<_> = string
-            .lines()
-            .flat_map(|line| line.chars().map(|c| mapper(c)))
-            .collect();
-        if width * height != entities.len() as u32 {
-            Err(())
-        } else {
-            Ok(Grid {
-                width,
-                height,
-                entities,
-            })
-        }
+        let rows = parse_grid(string, expected, mapper)?;
+        Ok(Grid {
+            width: rows[0].len() as u32,
+            height: rows.len() as u32,
+            entities: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn in_bounds(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    fn index(&self, position: Position) -> Option<usize> {
+        self.in_bounds(position)
+            .then(|| (position.x + position.y * self.width) as usize)
     }
 
     pub fn get(&self, position: Position) -> Option<&T> {
-        if position.x > self.width || position.y > self.height {
-            None
-        } else {
-            self.entities
-                .get((position.x + position.y * self.width) as usize)
-        }
+        self.index(position).map(|i| &self.entities[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index(position).map(|i| &mut self.entities[i])
     }
 
+    /// Panics if `position` is outside of the grid.
     pub fn set(&mut self, position: Position, new: T) {
-        self.entities[(position.x + position.y * self.width) as usize] = new;
+        *self
+            .get_mut(position)
+            .expect("position outside of the grid") = new;
+    }
+
+    /// Position one step into `direction`, `None` if that leaves the grid.
+    pub fn try_move(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.try_offset(position, direction.offset())
+    }
+
+    /// Position `offset` away, `None` if that leaves the grid.
+    pub fn try_offset(&self, position: Position, offset: Offset) -> Option<Position> {
+        position
+            .checked_add(offset)
+            .filter(|&position| self.in_bounds(position))
+    }
+
+    /// In-bounds orthogonal neighbours of `position` with their values.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.neighbours(position, Direction::all().map(Offset::from))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `position` with their values.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.neighbours(position, Direction8::ALL.map(Offset::from))
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        position: Position,
+        offsets: [Offset; N],
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        offsets.into_iter().filter_map(move |offset| {
+            let neighbour = self.try_offset(position, offset)?;
+            Some((neighbour, self.get(neighbour)?))
+        })
+    }
+
+    /// Positions from `start` on, repeatedly stepping by `step`, until the grid is left. The
+    /// ray includes `start` if it is inside the grid.
+    pub fn ray(
+        &self,
+        start: Position,
+        step: impl Into<Offset>,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let step = step.into();
+        let start = Some(start).filter(|&start| self.in_bounds(start));
+        std::iter::successors(start, move |&position| self.try_offset(position, step))
     }
 
-    pub fn find<'a>(&self, object: &'a T) -> impl Iterator<Item=Position> + use < 'a, '_, T > {
+    pub fn find<'a>(&self, object: &'a T) -> impl Iterator<Item = Position> + use<'a, '_, T> {
         self.entities
             .iter()
             .positions(|entity| *entity == *object)
@@ -154,18 +425,448 @@ impl<T: PartialEq> Grid<T> {
             })
     }
 
-    pub fn pretty_print<F>(&self, render: F) -> String
+    /// Orthogonally connected regions of equal entities, every position belongs to one.
+    pub fn regions(&self) -> Regions {
+        Regions::of_equal(self.width as usize, self.height as usize, |(x, y)| {
+            self.at(x as u32, y as u32)
+        })
+    }
+
+    /// Orthogonally connected regions of the entities for which `member` holds.
+    pub fn regions_where(&self, member: impl Fn(&T) -> bool) -> Regions {
+        Regions::matching(self.width as usize, self.height as usize, |(x, y)| {
+            member(self.at(x as u32, y as u32))
+        })
+    }
+
+    /// Renders the grid for printing or drawing, `render` gives the glyph and colour of a cell.
+    pub fn render<C: Into<Cell>>(&self, render: impl Fn(Position, &T) -> C) -> Frame {
+        Frame::new(self.width as usize, self.height as usize, |x, y| {
+            let pos = Position::new(x as u32, y as u32);
+            render(pos, self.get(pos).unwrap())
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with the entity at each position given by `entity`.
+    pub fn from_fn(width: u32, height: u32, mut entity: impl FnMut(Position) -> T) -> Grid<T> {
+        let entities = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Position::new(x, y)))
+            .map(&mut entity)
+            .collect();
+        Grid {
+            width,
+            height,
+            entities,
+        }
+    }
+
+    fn at(&self, x: u32, y: u32) -> &T {
+        &self.entities[(x + y * self.width) as usize]
+    }
+
+    /// Row `y` from left to right, `None` if it is outside of the grid.
+    pub fn row(&self, y: u32) -> Option<&[T]> {
+        (y < self.height).then(|| {
+            let start = (y * self.width) as usize;
+            &self.entities[start..start + self.width as usize]
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y).unwrap())
+    }
+
+    /// Column `x` from top to bottom, `None` if it is outside of the grid.
+    pub fn column(&self, x: u32) -> Option<impl Iterator<Item = &T> + '_> {
+        (x < self.width).then(|| (0..self.height).map(move |y| self.at(x, y)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x).unwrap())
+    }
+
+    /// The `width` x `height` rectangle with its top left corner at `top_left`, without copying
+    /// it. `None` if the rectangle does not fit into the grid.
+    pub fn window(&self, top_left: Position, width: u32, height: u32) -> Option<Window<'_, T>> {
+        let right = top_left.x.checked_add(width)?;
+        let bottom = top_left.y.checked_add(height)?;
+        (right <= self.width && bottom <= self.height).then_some(Window {
+            grid: self,
+            top_left,
+            width,
+            height,
+        })
+    }
+}
+
+/// Read access shared by the dense [`Grid`] and the [`SparseGrid`](crate::sparse_grid::SparseGrid),
+/// so that searches and rendering work on either. A sparse grid only has entities where something
+/// was put, its other positions inside the bounds are empty.
+pub trait GridRead {
+    type Position: Copy + Eq + Hash + Sub<Output = Offset>;
+    type Entity;
+
+    fn get(&self, position: Self::Position) -> Option<&Self::Entity>;
+
+    fn in_bounds(&self, position: Self::Position) -> bool;
+
+    /// Position `offset` away, `None` if that leaves the grid.
+    fn try_offset(&self, position: Self::Position, offset: Offset) -> Option<Self::Position>;
+
+    /// Top left and bottom right position of the area that is rendered, `None` if there is
+    /// nothing to render.
+    fn extent(&self) -> Option<(Self::Position, Self::Position)>;
+
+    /// Positions that hold an entity, with the entity.
+    fn entities(&self) -> impl Iterator<Item = (Self::Position, &Self::Entity)> + '_;
+
+    /// Position one step into `direction`, `None` if that leaves the grid.
+    fn try_move(&self, position: Self::Position, direction: Direction) -> Option<Self::Position> {
+        self.try_offset(position, direction.offset())
+    }
+
+    /// In-bounds orthogonal neighbours of `position` that hold an entity, with the entity. On a
+    /// small grid that wraps around, several directions can lead to the same neighbour, it is
+    /// only returned once.
+    fn neighbours4(
+        &self,
+        position: Self::Position,
+    ) -> impl Iterator<Item = (Self::Position, &Self::Entity)> + '_ {
+        Direction::all()
+            .into_iter()
+            .filter_map(move |direction| {
+                let neighbour = self.try_move(position, direction)?;
+                Some((neighbour, self.get(neighbour)?))
+            })
+            .unique_by(|&(neighbour, _)| neighbour)
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `position` that hold an entity, with the
+    /// entity. Like [`GridRead::neighbours4`] every neighbour is returned once.
+    fn neighbours8(
+        &self,
+        position: Self::Position,
+    ) -> impl Iterator<Item = (Self::Position, &Self::Entity)> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| {
+                let neighbour = self.try_offset(position, direction.offset())?;
+                Some((neighbour, self.get(neighbour)?))
+            })
+            .unique_by(|&(neighbour, _)| neighbour)
+    }
+
+    fn find<'a>(&'a self, object: &'a Self::Entity) -> impl Iterator<Item = Self::Position> + 'a
     where
-        F: Fn(Position, &T) -> char,
+        Self::Entity: PartialEq,
     {
-        let mut result = String::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                let pos = Position { x, y };
-                result.push(render(pos, self.get(pos).unwrap()))
-            }
-            result.push('\n');
-        }
-        result
+        self.entities()
+            .filter(move |(_, entity)| *entity == object)
+            .map(|(position, _)| position)
+    }
+
+    /// Renders the [`GridRead::extent`] of the grid, `render` gives the glyph and colour of a
+    /// position and gets `None` for empty positions.
+    fn render_extent<C: Into<Cell>>(
+        &self,
+        render: impl Fn(Self::Position, Option<&Self::Entity>) -> C,
+    ) -> Frame {
+        let Some((top_left, bottom_right)) = self.extent() else {
+            return Frame::new(0, 0, |_, _| ' ');
+        };
+        let size = bottom_right - top_left;
+        Frame::new(size.dx as usize + 1, size.dy as usize + 1, |x, y| {
+            let position = self
+                .try_offset(top_left, Offset::new(x as i32, y as i32))
+                .unwrap();
+            render(position, self.get(position))
+        })
+    }
+}
+
+impl<T: PartialEq> GridRead for Grid<T> {
+    type Position = Position;
+    type Entity = T;
+
+    fn get(&self, position: Position) -> Option<&T> {
+        Grid::get(self, position)
+    }
+
+    fn in_bounds(&self, position: Position) -> bool {
+        Grid::in_bounds(self, position)
+    }
+
+    fn try_offset(&self, position: Position, offset: Offset) -> Option<Position> {
+        Grid::try_offset(self, position, offset)
+    }
+
+    fn extent(&self) -> Option<(Position, Position)> {
+        (self.width > 0 && self.height > 0).then(|| {
+            (
+                Position::new(0, 0),
+                Position::new(self.width - 1, self.height - 1),
+            )
+        })
+    }
+
+    fn entities(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.entities.iter().enumerate().map(|(i, entity)| {
+            let position = Position::new(i as u32 % self.width, i as u32 / self.width);
+            (position, entity)
+        })
+    }
+}
+
+/// Rotations and flips turn the whole grid, positions of the result are counted from its own
+/// top left corner.
+impl<T: Clone> Grid<T> {
+    /// The grid turned clockwise by a quarter, the left column becomes the top row.
+    pub fn rotate90(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| {
+            self.at(pos.y, self.height - 1 - pos.x).clone()
+        })
+    }
+
+    pub fn rotate180(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self.at(self.width - 1 - pos.x, self.height - 1 - pos.y)
+                .clone()
+        })
+    }
+
+    /// The grid turned counterclockwise by a quarter, the top row becomes the left column.
+    pub fn rotate270(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| {
+            self.at(self.width - 1 - pos.y, pos.x).clone()
+        })
+    }
+
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |pos| self.at(pos.y, pos.x).clone())
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self.at(self.width - 1 - pos.x, pos.y).clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self.at(pos.x, self.height - 1 - pos.y).clone()
+        })
+    }
+
+    /// A copy of a rectangle of the grid, see [`Grid::window`].
+    pub fn crop(&self, top_left: Position, width: u32, height: u32) -> Option<Grid<T>> {
+        self.window(top_left, width, height)
+            .map(|window| window.to_grid())
+    }
+}
+
+/// A rectangle of a [`Grid`], positions are counted from its own top left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct Window<'a, T> {
+    grid: &'a Grid<T>,
+    top_left: Position,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl<'a, T> Window<'a, T> {
+    pub fn get(&self, position: Position) -> Option<&'a T> {
+        (position.x < self.width && position.y < self.height).then(|| {
+            self.grid
+                .at(self.top_left.x + position.x, self.top_left.y + position.y)
+        })
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let Window {
+            grid,
+            top_left,
+            width,
+            height,
+        } = *self;
+        (top_left.y..top_left.y + height)
+            .map(move |y| &grid.row(y).unwrap()[top_left.x as usize..(top_left.x + width) as usize])
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self.get(pos).unwrap().clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::render::Rgb;
+
+    fn grid() -> Grid<char> {
+        Grid::parse_from_string("ab\ncd\n", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn get_is_bounds_checked() {
+        let grid = grid();
+        assert_eq!(grid.get(Position::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get(Position::new(2, 0)), None);
+        assert_eq!(grid.get(Position::new(0, 2)), None);
+    }
+
+    #[test]
+    fn try_move_stays_inside_grid() {
+        let grid = grid();
+        let corner = Position::new(0, 0);
+        assert_eq!(grid.try_move(corner, Direction::Up), None);
+        assert_eq!(grid.try_move(corner, Direction::Left), None);
+        assert_eq!(
+            grid.try_move(corner, Direction::Right),
+            Some(Position::new(1, 0))
+        );
+        assert_eq!(grid.try_move(Position::new(1, 1), Direction::Down), None);
+        assert_eq!(grid.try_offset(corner, Offset::new(1, 1) * 2), None);
+    }
+
+    #[test]
+    fn neighbours_at_the_edge() {
+        let grid = grid();
+        let corner = Position::new(0, 0);
+        let neighbours4: Vec<_> = grid.neighbours4(corner).map(|(_, &c)| c).collect();
+        assert_eq!(neighbours4, ['c', 'b']);
+        let neighbours8: Vec<_> = grid.neighbours8(corner).map(|(_, &c)| c).collect();
+        assert_eq!(neighbours8, ['b', 'd', 'c']);
+    }
+
+    #[test]
+    fn ray_stops_at_the_edge() {
+        let grid: Grid<char> =
+            Grid::parse_from_string("abc\ndef\nghi\n", "a letter", Some).unwrap();
+        let letters = |ray: Vec<Position>| -> String {
+            ray.into_iter().map(|pos| *grid.get(pos).unwrap()).collect()
+        };
+        let center = Position::new(1, 1);
+        assert_eq!(
+            letters(grid.ray(center, Direction8::UpLeft).collect()),
+            "ea"
+        );
+        assert_eq!(letters(grid.ray(center, Direction::Right).collect()), "ef");
+        assert_eq!(
+            letters(grid.ray(Position::new(0, 0), Offset::new(2, 1)).collect()),
+            "af"
+        );
+        assert_eq!(grid.ray(Position::new(3, 0), Direction::Left).count(), 0);
+    }
+
+    #[test]
+    fn offsets_between_positions() {
+        let a = Position::new(3, 1);
+        let b = Position::new(1, 2);
+        assert_eq!(b - a, Offset::new(-2, 1));
+        assert_eq!(a.checked_add(b - a), Some(b));
+        assert_eq!(b.checked_add(-(b - a) * 2), Some(Position::new(5, 0)));
+        assert_eq!(b.checked_add(Offset::new(-2, 0)), None);
+    }
+
+    #[test]
+    fn render_with_colours_and_path() {
+        let grid = grid();
+        let mut frame = grid.render(|pos, &c| match pos.x {
+            0 => Cell::coloured(c, Rgb::RED),
+            _ => Cell::new(c),
+        });
+        assert_eq!(frame.to_string(), "ab\ncd\n");
+        frame.overlay([Position::new(1, 1)], Rgb::BLUE);
+        assert_eq!(frame.cell(0, 1), Some(&Cell::coloured('c', Rgb::RED)));
+        assert!(frame.ansi().ends_with("\x1b[48;2;38;139;210md\x1b[0m\n"));
+    }
+
+    #[test]
+    fn rotations_and_flips() {
+        let grid: Grid<char> = Grid::parse_from_string("abc\ndef\n", "a letter", Some).unwrap();
+        let text = |grid: Grid<char>| -> String {
+            grid.rows()
+                .map(|row| row.iter().collect::<String>())
+                .join("\n")
+        };
+        assert_eq!(text(grid.rotate90()), "da\neb\nfc");
+        assert_eq!(text(grid.rotate180()), "fed\ncba");
+        assert_eq!(text(grid.rotate270()), "cf\nbe\nad");
+        assert_eq!(text(grid.transpose()), "ad\nbe\ncf");
+        assert_eq!(text(grid.flip_horizontal()), "cba\nfed");
+        assert_eq!(text(grid.flip_vertical()), "def\nabc");
+        assert_eq!(
+            text(grid.rotate90().rotate90().rotate90().rotate90()),
+            text(grid.clone())
+        );
+    }
+
+    #[test]
+    fn rows_columns_and_windows() {
+        let grid: Grid<char> =
+            Grid::parse_from_string("abc\ndef\nghi\n", "a letter", Some).unwrap();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(3), None);
+        let column: String = grid.column(2).unwrap().collect();
+        assert_eq!(column, "cfi");
+        assert!(grid.column(3).is_none());
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["adg", "beh", "cfi"]);
+
+        let window = grid.window(Position::new(1, 1), 2, 2).unwrap();
+        assert_eq!(window.get(Position::new(1, 0)), Some(&'f'));
+        assert_eq!(window.get(Position::new(2, 0)), None);
+        let rows: Vec<String> = window.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["ef", "hi"]);
+        assert!(grid.window(Position::new(2, 0), 2, 1).is_none());
+        assert!(grid.window(Position::new(2, 0), u32::MAX, 1).is_none());
+        let cropped = grid.crop(Position::new(0, 2), 3, 1).unwrap();
+        assert_eq!((cropped.width, cropped.height), (3, 1));
+        assert_eq!(cropped.entities, ['g', 'h', 'i']);
+    }
+
+    #[test]
+    fn regions_of_matching_entities() {
+        let grid: Grid<char> = Grid::parse_from_string("#.#\n##.\n", "'#' or '.'", |c| {
+            matches!(c, '#' | '.').then_some(c)
+        })
+        .unwrap();
+        assert_eq!(grid.regions().regions().len(), 4);
+        let walls = grid.regions_where(|&c| c == '#');
+        let areas: Vec<_> = walls.iter().map(|region| region.area()).collect();
+        assert_eq!(areas, [3, 1]);
+        assert_eq!(walls.label(Position::new(1, 0).into()), None);
+    }
+
+    #[test]
+    fn torus_wraps_around_the_edges() {
+        let torus = Torus::new(11, 7);
+        let start = Position::new(2, 4);
+        assert_eq!(torus.move_to(start, Direction::Down), Position::new(2, 5));
+        assert_eq!(
+            torus.offset(start, Offset::new(2, -3) * 5),
+            Position::new(1, 3)
+        );
+        assert_eq!(torus.wrap(Point::new(-1, 7)), Position::new(10, 0));
+        assert_eq!(
+            torus.offset(start, Offset::new(i32::MAX, i32::MIN)),
+            torus.wrap(Point::new(2 + i32::MAX % 11, 4 + i32::MIN % 7))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a torus needs a width and height of at least 1")]
+    fn empty_torus() {
+        Torus::new(5, 0);
     }
 }
@@ -1,28 +1,268 @@
-mod day0;
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day18;
-mod day2;
-mod day22;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod grid;
-mod day23;
-mod day21;
-mod day24;
-mod day25;
-
-fn main() {
-    day25::main();
+use aoc_common::bench::{self, Benchmark};
+use aoc_common::Inputs;
+use clap::Parser;
+use rust::playback;
+use rust::registry::{self, Day, ExportFormat};
+use std::fs;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Runs Advent of Code 2024 solutions.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Day to run.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Only run this part of the day instead of both.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file, "-" reads stdin. By default the input is looked up in the inputs directory.
+    #[arg(long, conflicts_with_all = ["all", "example"])]
+    input: Option<String>,
+
+    /// Directory with the puzzle inputs, overrides the AOC_INPUTS environment variable.
+    #[arg(long, value_name = "DIR")]
+    inputs: Option<String>,
+
+    /// Use the named example, e.g. "day<N>_<NAME>.txt", instead of the real input.
+    #[arg(long, value_name = "NAME")]
+    example: Option<String>,
+
+    /// Run every implemented day with its default input file.
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+
+    /// Time parsing and both parts instead of printing the answers.
+    #[arg(long, conflicts_with = "part")]
+    bench: bool,
+
+    /// Number of benchmark runs per day, the table shows the median.
+    #[arg(long, default_value_t = 10, requires = "bench", value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Also write the benchmark results as JSON to this file.
+    #[arg(long, requires = "bench")]
+    json: Option<String>,
+
+    /// Also write the benchmark results as CSV to this file.
+    #[arg(long, requires = "bench")]
+    csv: Option<String>,
+
+    /// Play back the simulation of the day (6, 14 or 15) step by step. Commands such as pausing
+    /// or jumping to a step are read from stdin while it plays.
+    #[arg(long, conflicts_with_all = ["all", "bench"])]
+    play: bool,
+
+    /// Milliseconds between two steps of the playback.
+    #[arg(long, value_name = "MS", default_value_t = 100, requires = "play")]
+    delay: u64,
+
+    /// Start the playback paused.
+    #[arg(long, requires = "play")]
+    paused: bool,
+
+    /// Size of the room the robots of day 14 move in, e.g. "11x7" for the example. By default
+    /// it is the 101x103 room of the puzzle.
+    #[arg(long, value_name = "WxH", value_parser = parse_room, conflicts_with_all = ["bench", "export", "render"])]
+    room: Option<(u32, u32)>,
+
+    /// Write the input of the day (17 or 24) to this file for inspection, as a Graphviz graph if
+    /// it ends in ".dot" and as text otherwise.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["all", "bench", "play"])]
+    export: Option<String>,
+
+    /// Export the analysis of the input (day 24) as text instead of the input itself.
+    #[arg(long, requires = "export")]
+    report: bool,
+
+    /// Draw the solution of the day (12 or 16) to this image file, as PPM if it ends in ".ppm"
+    /// and as PNG otherwise.
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["all", "bench", "play", "export"])]
+    render: Option<String>,
+
+    /// Side length in pixels of one grid cell in the rendered image.
+    #[arg(long, value_name = "N", default_value_t = 8, requires = "render", value_parser = clap::value_parser!(u32).range(1..))]
+    cell_size: u32,
+}
+
+fn parse_room(room: &str) -> Result<(u32, u32), String> {
+    let size = |size: &str| size.parse::<u32>().ok().filter(|&size| size > 0);
+    room.split_once('x')
+        .and_then(|(width, height)| Some((size(width)?, size(height)?)))
+        .ok_or_else(|| format!("expected a size like \"101x103\", found {:?}", room))
+}
+
+fn run_day(day: &Day, cli: &Cli, inputs: &mut Inputs, file: Option<&str>) -> Result<(), String> {
+    let parts = match cli.part {
+        Some(part) if !day.has_part(part) => {
+            return Err(format!(
+                "part {} of day {} is not implemented",
+                part, day.number
+            ))
+        }
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let (input, file_contents) = inputs
+        .load(day.number, file)
+        .map_err(|err| err.to_string())?;
+    let parsed = day
+        .parse(file_contents, cli.room)
+        .map_err(|err| err.with_file(input).to_string())?;
+    for part in parts {
+        let answer = match part {
+            1 => day.solution.part1_dyn(parsed.as_ref()),
+            _ => day.solution.part2_dyn(parsed.as_ref()),
+        };
+        if let Some(answer) = answer {
+            println!("Star {}: {}", part, answer);
+        }
+    }
+    Ok(())
+}
+
+fn selected_day(cli: &Cli) -> Result<&'static Day, String> {
+    let number = cli.day.expect("clap requires --day without --all");
+    registry::find_day(number).ok_or_else(|| {
+        format!(
+            "day {} is not implemented, available days are {}",
+            number,
+            registry::implemented_days()
+        )
+    })
+}
+
+fn bench_day(
+    day: &Day,
+    inputs: &mut Inputs,
+    file: Option<&str>,
+    runs: usize,
+) -> Result<Benchmark, String> {
+    let (input, file_contents) = inputs
+        .load(day.number, file)
+        .map_err(|err| err.to_string())?;
+    bench::bench(day.number, day.solution, file_contents, runs)
+        .map_err(|err| err.with_file(input).to_string())
+}
+
+fn write_report(file: &str, report: &str) -> Result<(), String> {
+    fs::write(file, report).map_err(|err| format!("cannot write {}: {}", file, err))
+}
+
+fn run_bench(cli: &Cli, inputs: &mut Inputs) -> Result<(), String> {
+    let runs = cli.runs as usize;
+    let mut benchmarks = Vec::new();
+    if cli.all {
+        for day in registry::DAYS {
+            match bench_day(day, inputs, None, runs) {
+                Ok(benchmark) => benchmarks.push(benchmark),
+                Err(message) => eprintln!("skipped day {}: {}", day.number, message),
+            }
+        }
+    } else {
+        let day = selected_day(cli)?;
+        benchmarks.push(bench_day(day, inputs, cli.input.as_deref(), runs)?);
+    }
+
+    print!("{}", bench::table(&benchmarks));
+    if let Some(file) = &cli.json {
+        write_report(file, &bench::json(&benchmarks))?;
+    }
+    if let Some(file) = &cli.csv {
+        write_report(file, &bench::csv(&benchmarks))?;
+    }
+    Ok(())
+}
+
+fn play_day(day: &Day, cli: &Cli, inputs: &mut Inputs) -> Result<(), String> {
+    let play = day
+        .play
+        .ok_or_else(|| format!("day {} has no playback", day.number))?;
+    let (input, file_contents) = inputs
+        .load(day.number, cli.input.as_deref())
+        .map_err(|err| err.to_string())?;
+    let parsed = day
+        .parse(file_contents, cli.room)
+        .map_err(|err| err.with_file(input).to_string())?;
+    let options = playback::Options {
+        part: cli.part.unwrap_or(1),
+        delay: Duration::from_millis(cli.delay),
+        paused: cli.paused,
+    };
+    play(parsed.as_ref(), &options);
+    Ok(())
+}
+
+fn export_day(day: &Day, cli: &Cli, file: &str, inputs: &mut Inputs) -> Result<(), String> {
+    let export = day
+        .export
+        .ok_or_else(|| format!("day {} has no export", day.number))?;
+    let (input, file_contents) = inputs
+        .load(day.number, cli.input.as_deref())
+        .map_err(|err| err.to_string())?;
+    let format = if cli.report {
+        ExportFormat::Report
+    } else if file.ends_with(".dot") {
+        ExportFormat::Dot
+    } else {
+        ExportFormat::Text
+    };
+    let exported = export(file_contents, format)
+        .map_err(|err| err.with_file(input).to_string())?
+        .ok_or_else(|| format!("day {} has no {:?} export", day.number, format))?;
+    write_report(file, &exported)
+}
+
+fn render_day(day: &Day, cli: &Cli, file: &str, inputs: &mut Inputs) -> Result<(), String> {
+    let render = day
+        .render
+        .ok_or_else(|| format!("day {} has no rendering", day.number))?;
+    let (input, file_contents) = inputs
+        .load(day.number, cli.input.as_deref())
+        .map_err(|err| err.to_string())?;
+    let frame = render(file_contents).map_err(|err| err.with_file(input).to_string())?;
+    frame
+        .image(cli.cell_size as usize)
+        .write(file)
+        .map_err(|err| format!("cannot write {}: {}", file, err))
+}
+
+fn run(cli: &Cli) -> Result<(), String> {
+    let mut inputs = Inputs::new(cli.inputs.as_deref(), ".").with_example(cli.example.as_deref());
+    if cli.bench {
+        run_bench(cli, &mut inputs)
+    } else if cli.play {
+        play_day(selected_day(cli)?, cli, &mut inputs)
+    } else if let Some(file) = &cli.export {
+        export_day(selected_day(cli)?, cli, file, &mut inputs)
+    } else if let Some(file) = &cli.render {
+        render_day(selected_day(cli)?, cli, file, &mut inputs)
+    } else if cli.all {
+        for day in registry::DAYS {
+            if cli.part.is_some_and(|part| !day.has_part(part)) {
+                continue;
+            }
+            println!("Day {}", day.number);
+            if let Err(message) = run_day(day, cli, &mut inputs, None) {
+                println!("skipped: {}", message);
+            }
+        }
+        Ok(())
+    } else {
+        let day = selected_day(cli)?;
+        run_day(day, cli, &mut inputs, cli.input.as_deref())
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
 }
@@ -0,0 +1,286 @@
+//! Step-by-step playback of simulations in the terminal. Commands are read line by line from
+//! stdin while the simulation plays, so the playback can be paused, stepped in both directions
+//! and jumped to any step.
+
+use aoc_common::render::Frame;
+use aoc_common::Solution;
+use std::any::Any;
+use std::io::{self, BufRead};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// A simulation that can be played back. Stepping back replays from a clone of the initial
+/// state, so simulations only need to step forward.
+pub(crate) trait Simulation: Clone {
+    /// Advances the simulation by one step, `false` if it has already ended.
+    fn step(&mut self) -> bool;
+
+    fn render(&self) -> Frame;
+
+    /// Additional information shown below the frame.
+    fn status(&self) -> String {
+        String::new()
+    }
+}
+
+/// A day whose solution can be watched as a simulation.
+pub(crate) trait Animated: Solution {
+    type Simulation: Simulation;
+
+    /// The simulation behind `part` of the day, starting from the parsed input.
+    fn simulation(input: &Self::Input, part: u8) -> Self::Simulation;
+}
+
+/// Plays back the simulation of a day from its parsed input, see [`play`].
+pub type Player = fn(input: &dyn Any, options: &Options);
+
+/// Shortest delay between two steps, without one the playback would spin instead of waiting
+/// for commands.
+const MIN_DELAY: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Copy, Clone)]
+pub struct Options {
+    pub part: u8,
+    pub delay: Duration,
+    pub paused: bool,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Command {
+    TogglePause,
+    Forward,
+    Back,
+    Jump(usize),
+    Faster,
+    Slower,
+    Quit,
+}
+
+const HELP: &str =
+    "[enter] pause/resume  n next  b back  g <step> jump  + faster  - slower  q quit";
+
+fn parse_command(line: &str) -> Option<Command> {
+    let mut words = line.split_whitespace();
+    let command = match words.next() {
+        None => Command::TogglePause,
+        Some("p") => Command::TogglePause,
+        Some("n") => Command::Forward,
+        Some("b") => Command::Back,
+        Some("g") => Command::Jump(words.next()?.parse().ok()?),
+        Some("+") => Command::Faster,
+        Some("-") => Command::Slower,
+        Some("q") => Command::Quit,
+        Some(_) => return None,
+    };
+    words.next().is_none().then_some(command)
+}
+
+struct Playback<S> {
+    initial: S,
+    current: S,
+    step: usize,
+    finished: bool,
+    paused: bool,
+    delay: Duration,
+}
+
+impl<S: Simulation> Playback<S> {
+    fn new(initial: S, options: &Options) -> Playback<S> {
+        Playback {
+            current: initial.clone(),
+            initial,
+            step: 0,
+            finished: false,
+            paused: options.paused,
+            delay: options.delay.max(MIN_DELAY),
+        }
+    }
+
+    fn forward(&mut self) {
+        if self.current.step() {
+            self.step += 1;
+        } else {
+            self.finished = true;
+        }
+    }
+
+    fn jump(&mut self, step: usize) {
+        if step < self.step {
+            self.current = self.initial.clone();
+            self.step = 0;
+            self.finished = false;
+        }
+        while self.step < step && !self.finished {
+            self.forward();
+        }
+    }
+
+    /// Applies `command`, returns `false` if the playback should end.
+    fn apply(&mut self, command: Command) -> bool {
+        match command {
+            Command::TogglePause => self.paused = !self.paused,
+            Command::Forward => {
+                self.paused = true;
+                self.forward();
+            }
+            Command::Back => {
+                self.paused = true;
+                self.jump(self.step.saturating_sub(1));
+            }
+            Command::Jump(step) => {
+                self.paused = true;
+                self.jump(step);
+            }
+            Command::Faster => self.delay = (self.delay / 2).max(MIN_DELAY),
+            Command::Slower => self.delay = (self.delay * 2).max(MIN_DELAY),
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    fn draw(&self, message: &str) {
+        let state = if self.finished {
+            "finished"
+        } else if self.paused {
+            "paused"
+        } else {
+            "playing"
+        };
+        // Clear the screen and move the cursor to the top left corner.
+        print!("\x1b[2J\x1b[H{}", self.current.render().ansi());
+        println!(
+            "step {} ({}, {:?} per step) {}",
+            self.step,
+            state,
+            self.delay,
+            self.current.status()
+        );
+        println!("{HELP}");
+        if !message.is_empty() {
+            println!("{message}");
+        }
+    }
+}
+
+fn read_commands() -> Receiver<String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+/// Plays back the simulation of `D` on the input. The playback runs until the simulation has
+/// ended, or until it is quit if commands can be read from stdin.
+pub(crate) fn play<D: Animated>(input: &dyn Any, options: &Options) {
+    let input = input
+        .downcast_ref::<D::Input>()
+        .expect("input was parsed by a different solution");
+    let mut playback = Playback::new(D::simulation(input, options.part), options);
+    let commands = read_commands();
+    let mut input_closed = false;
+    let mut message = String::new();
+    loop {
+        playback.draw(&message);
+        message.clear();
+        let waiting = playback.paused || playback.finished;
+        let line = if input_closed {
+            if waiting {
+                break;
+            }
+            thread::sleep(playback.delay);
+            None
+        } else if waiting {
+            commands.recv().ok()
+        } else {
+            match commands.recv_timeout(playback.delay) {
+                Ok(line) => Some(line),
+                Err(RecvTimeoutError::Timeout) => None,
+                Err(RecvTimeoutError::Disconnected) => {
+                    input_closed = true;
+                    continue;
+                }
+            }
+        };
+        match line {
+            Some(line) => match parse_command(&line) {
+                Some(command) => {
+                    if !playback.apply(command) {
+                        break;
+                    }
+                }
+                None => message = format!("unknown command {line:?}"),
+            },
+            None if waiting => input_closed = true,
+            None => playback.forward(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts up to three.
+    #[derive(Clone)]
+    struct Counter(u32);
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.0 == 3 {
+                return false;
+            }
+            self.0 += 1;
+            true
+        }
+
+        fn render(&self) -> Frame {
+            Frame::new(1, 1, |_, _| char::from_digit(self.0, 10).unwrap())
+        }
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(parse_command(""), Some(Command::TogglePause));
+        assert_eq!(parse_command(" g 12 "), Some(Command::Jump(12)));
+        assert_eq!(parse_command("g"), None);
+        assert_eq!(parse_command("g x"), None);
+        assert_eq!(parse_command("n 2"), None);
+        assert_eq!(parse_command("q"), Some(Command::Quit));
+    }
+
+    #[test]
+    fn step_back_and_jump() {
+        let options = Options {
+            part: 1,
+            delay: Duration::from_millis(100),
+            paused: false,
+        };
+        let mut playback = Playback::new(Counter(0), &options);
+        playback.apply(Command::Forward);
+        playback.apply(Command::Forward);
+        assert!(playback.paused);
+        assert_eq!((playback.step, playback.current.0), (2, 2));
+        playback.apply(Command::Back);
+        assert_eq!((playback.step, playback.current.0), (1, 1));
+        playback.apply(Command::Jump(10));
+        assert!(playback.finished);
+        assert_eq!(playback.step, 3);
+        playback.apply(Command::Jump(0));
+        assert!(!playback.finished);
+        assert_eq!(playback.current.render().to_string(), "0\n");
+        playback.apply(Command::Faster);
+        assert_eq!(playback.delay, Duration::from_millis(50));
+        for _ in 0..10 {
+            playback.apply(Command::Faster);
+        }
+        assert_eq!(playback.delay, MIN_DELAY);
+        assert!(!playback.apply(Command::Quit));
+    }
+}
@@ -0,0 +1,178 @@
+use aoc_common::render::Frame;
+use aoc_common::{DynSolution, ParseError};
+use itertools::Itertools;
+use std::any::Any;
+
+use crate::playback::{self, Player};
+use crate::{
+    day1, day10, day11, day12, day13, day14, day15, day16, day17, day18, day2, day21, day22, day23,
+    day24, day25, day3, day4, day5, day6, day7, day8, day9,
+};
+
+/// How a day's input is exported for inspection.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ExportFormat {
+    /// A Graphviz graph.
+    Dot,
+    /// Plain text.
+    Text,
+    /// Plain text on what the analysis of the input found, for the days that analyse it.
+    Report,
+}
+
+/// Parses the input and formats it for inspection outside of the solution, `None` if the day
+/// does not support `format`.
+pub type Exporter =
+    fn(file_contents: &str, format: ExportFormat) -> Result<Option<String>, ParseError>;
+
+/// Parses the input and draws the solution on top of it.
+pub type Renderer = fn(file_contents: &str) -> Result<Frame, ParseError>;
+
+/// Changes the size of the room a parsed input takes place in, given as width and height.
+pub type Resizer = fn(input: &mut dyn Any, room: (u32, u32));
+
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub number: u8,
+    pub solution: &'static dyn DynSolution,
+    /// Plays back the simulation of the day, for the days that are simulations.
+    pub play: Option<Player>,
+    /// Exports the input, for the days whose input is worth looking at as a whole.
+    pub export: Option<Exporter>,
+    /// Draws the solution, for the days whose solution is best seen as a picture.
+    pub render: Option<Renderer>,
+    /// Sets the room size, for the days that take place in a room whose size is not part of the
+    /// input.
+    pub resize: Option<Resizer>,
+}
+
+impl Day {
+    pub fn default_input(&self) -> String {
+        format!("day{}_input.txt", self.number)
+    }
+
+    /// Parses the input, in `room` instead of the puzzle's room if the day has one.
+    pub fn parse(
+        &self,
+        file_contents: &str,
+        room: Option<(u32, u32)>,
+    ) -> Result<Box<dyn Any>, ParseError> {
+        let mut input = self.solution.parse_dyn(file_contents)?;
+        if let (Some(resize), Some(room)) = (self.resize, room) {
+            resize(input.as_mut(), room);
+        }
+        Ok(input)
+    }
+
+    pub fn has_part(&self, part: u8) -> bool {
+        match part {
+            1 => self.solution.has_part1(),
+            2 => self.solution.has_part2(),
+            _ => false,
+        }
+    }
+
+    const fn resizable(self, resize: Resizer) -> Day {
+        Day {
+            resize: Some(resize),
+            ..self
+        }
+    }
+}
+
+const fn day(number: u8, solution: &'static dyn DynSolution) -> Day {
+    Day {
+        number,
+        solution,
+        play: None,
+        export: None,
+        render: None,
+        resize: None,
+    }
+}
+
+const fn animated(number: u8, solution: &'static dyn DynSolution, play: Player) -> Day {
+    Day {
+        number,
+        solution,
+        play: Some(play),
+        export: None,
+        render: None,
+        resize: None,
+    }
+}
+
+const fn exported(number: u8, solution: &'static dyn DynSolution, export: Exporter) -> Day {
+    Day {
+        number,
+        solution,
+        play: None,
+        export: Some(export),
+        render: None,
+        resize: None,
+    }
+}
+
+const fn rendered(number: u8, solution: &'static dyn DynSolution, render: Renderer) -> Day {
+    Day {
+        number,
+        solution,
+        play: None,
+        export: None,
+        render: Some(render),
+        resize: None,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    day(1, &day1::Day1),
+    day(2, &day2::Day2),
+    day(3, &day3::Day3),
+    day(4, &day4::Day4),
+    day(5, &day5::Day5),
+    animated(6, &day6::Day6, playback::play::<day6::Day6>),
+    day(7, &day7::Day7),
+    day(8, &day8::Day8),
+    day(9, &day9::Day9),
+    day(10, &day10::Day10),
+    day(11, &day11::Day11),
+    rendered(12, &day12::Day12, day12::render),
+    day(13, &day13::Day13),
+    animated(14, &day14::Day14, playback::play::<day14::Day14>).resizable(day14::resize),
+    animated(15, &day15::Day15, playback::play::<day15::Day15>),
+    rendered(16, &day16::Day16, day16::render),
+    exported(17, &day17::Day17, day17::export),
+    day(18, &day18::Day18),
+    day(21, &day21::Day21),
+    day(22, &day22::Day22),
+    day(23, &day23::Day23),
+    exported(24, &day24::Day24, day24::export),
+    day(25, &day25::Day25),
+];
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub fn implemented_days() -> String {
+    DAYS.iter().map(|day| day.number).join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `dayN_input.txt` next to the recorded `dayN_answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_inputs_match_recorded_answers() {
+        for day in DAYS {
+            aoc_common::check_recorded_answers(
+                day.solution,
+                &day.default_input(),
+                &format!("day{}_answers.txt", day.number),
+            );
+        }
+    }
+}
@@ -0,0 +1,238 @@
+#![allow(dead_code)]
+
+use crate::grid::{Grid, GridRead, Offset, Point, Position, Torus};
+use std::collections::HashMap;
+
+/// A grid that only stores the positions that hold something, for maps that are mostly empty
+/// such as obstacles, antennas or robots. It is either bounded to `width` x `height` positions
+/// from `(0, 0)` like a [`Grid`], the same but with edges that wrap around, or unbounded in
+/// every direction including negative coordinates.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseGrid<T> {
+    size: Option<(u32, u32)>,
+    wraps: bool,
+    entities: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn bounded(width: u32, height: u32) -> SparseGrid<T> {
+        SparseGrid {
+            size: Some((width, height)),
+            wraps: false,
+            entities: HashMap::new(),
+        }
+    }
+
+    /// A bounded grid on which moves wrap around the edges, see [`Torus`].
+    pub fn toroidal(torus: Torus) -> SparseGrid<T> {
+        SparseGrid {
+            size: Some((torus.width, torus.height)),
+            wraps: true,
+            entities: HashMap::new(),
+        }
+    }
+
+    pub fn unbounded() -> SparseGrid<T> {
+        SparseGrid {
+            size: None,
+            wraps: false,
+            entities: HashMap::new(),
+        }
+    }
+
+    /// Width and height of a bounded grid.
+    pub fn size(&self) -> Option<(u32, u32)> {
+        self.size
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.entities.get_mut(&position)
+    }
+
+    /// Puts `entity` at `position` and returns the entity that was there before.
+    ///
+    /// Panics if `position` is outside of a bounded grid.
+    pub fn insert(&mut self, position: Point, entity: T) -> Option<T> {
+        assert!(self.in_bounds(position), "position outside of the grid");
+        self.entities.insert(position, entity)
+    }
+
+    pub fn remove(&mut self, position: Point) -> Option<T> {
+        self.entities.remove(&position)
+    }
+
+    /// Smallest rectangle around all entities, as top left and bottom right corner.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let xs = self.entities.keys().map(|point| point.x);
+        let ys = self.entities.keys().map(|point| point.y);
+        Some((
+            Point::new(xs.clone().min()?, ys.clone().min()?),
+            Point::new(xs.max()?, ys.max()?),
+        ))
+    }
+}
+
+impl<T> GridRead for SparseGrid<T> {
+    type Position = Point;
+    type Entity = T;
+
+    fn get(&self, position: Point) -> Option<&T> {
+        self.entities.get(&position)
+    }
+
+    fn in_bounds(&self, position: Point) -> bool {
+        match self.size {
+            Some((width, height)) => {
+                (0..width as i32).contains(&position.x) && (0..height as i32).contains(&position.y)
+            }
+            None => true,
+        }
+    }
+
+    /// On a toroidal grid this wraps around the edges and never fails.
+    fn try_offset(&self, position: Point, offset: Offset) -> Option<Point> {
+        match self.size {
+            Some((width, height)) if self.wraps => {
+                let torus = Torus::new(width, height);
+                Some(torus.offset(torus.wrap(position), offset).into())
+            }
+            _ => Some(position + offset).filter(|&position| self.in_bounds(position)),
+        }
+    }
+
+    /// The whole grid if it is bounded, the [`SparseGrid::bounding_box`] of the entities if not.
+    fn extent(&self) -> Option<(Point, Point)> {
+        match self.size {
+            Some((0, _) | (_, 0)) => None,
+            Some((width, height)) => Some((
+                Point::new(0, 0),
+                Point::new(width as i32 - 1, height as i32 - 1),
+            )),
+            None => self.bounding_box(),
+        }
+    }
+
+    fn entities(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.entities
+            .iter()
+            .map(|(&position, entity)| (position, entity))
+    }
+}
+
+/// An unbounded grid of the collected entities.
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(entities: I) -> SparseGrid<T> {
+        SparseGrid {
+            size: None,
+            wraps: false,
+            entities: entities.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A bounded sparse grid of the same size with the entities for which `keep` holds.
+    pub fn to_sparse(&self, keep: impl Fn(&T) -> bool) -> SparseGrid<T> {
+        let mut sparse = SparseGrid::bounded(self.width, self.height);
+        for (i, entity) in self.entities.iter().enumerate() {
+            if keep(entity) {
+                let position = Position::new(i as u32 % self.width, i as u32 / self.width);
+                sparse.insert(position.into(), entity.clone());
+            }
+        }
+        sparse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Direction;
+
+    #[test]
+    fn bounded_like_the_dense_grid() {
+        let dense: Grid<char> = Grid::parse_from_string("#.\n.#\n", "'#' or '.'", |c| {
+            matches!(c, '#' | '.').then_some(c)
+        })
+        .unwrap();
+        let sparse = dense.to_sparse(|&c| c == '#');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(Point::new(1, 1)), Some(&'#'));
+        assert_eq!(sparse.get(Point::new(1, 0)), None);
+        assert_eq!(sparse.try_move(Point::new(0, 0), Direction::Up), None);
+        let neighbours: Vec<_> = sparse.neighbours8(Point::new(0, 0)).collect();
+        assert_eq!(neighbours, [(Point::new(1, 1), &'#')]);
+
+        let render = |position, entity: Option<&char>| {
+            *entity.unwrap_or(if position == Point::new(1, 0) {
+                &'o'
+            } else {
+                &'.'
+            })
+        };
+        assert_eq!(sparse.render_extent(render).to_string(), "#o\n.#\n");
+        let dense_frame = dense.render_extent(|_, entity| *entity.unwrap());
+        assert_eq!(dense_frame.to_string(), "#.\n.#\n");
+    }
+
+    #[test]
+    fn unbounded_with_negative_coordinates() {
+        let mut sparse: SparseGrid<u32> = [(Point::new(-2, 1), 1), (Point::new(0, -1), 2)]
+            .into_iter()
+            .collect();
+        assert!(sparse.in_bounds(Point::new(-100, 100)));
+        assert_eq!(
+            sparse.bounding_box(),
+            Some((Point::new(-2, -1), Point::new(0, 1)))
+        );
+        *sparse.get_mut(Point::new(0, -1)).unwrap() += 5;
+        assert_eq!(sparse.find(&7).collect::<Vec<_>>(), [Point::new(0, -1)]);
+        let frame = sparse.render_extent(|_, count| match count {
+            Some(&count) => char::from_digit(count, 10).unwrap(),
+            None => '.',
+        });
+        assert_eq!(frame.to_string(), "..7\n...\n1..\n");
+        assert_eq!(sparse.remove(Point::new(-2, 1)), Some(1));
+        assert_eq!(
+            sparse.extent(),
+            Some((Point::new(0, -1), Point::new(0, -1)))
+        );
+    }
+
+    #[test]
+    fn toroidal_moves_wrap_around() {
+        let mut sparse = SparseGrid::toroidal(Torus::new(3, 2));
+        sparse.insert(Point::new(2, 1), 'x');
+        sparse.insert(Point::new(0, 1), 'y');
+        let corner = Point::new(0, 0);
+        assert_eq!(
+            sparse.try_move(corner, Direction::Up),
+            Some(Point::new(0, 1))
+        );
+        assert_eq!(
+            sparse.try_offset(corner, Offset::new(-4, 7)),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(
+            sparse.try_offset(corner, Offset::new(i32::MAX, i32::MIN)),
+            Some(Point::new(1, 0))
+        );
+        assert!(!sparse.in_bounds(Point::new(3, 0)));
+        // Up and down both wrap to the same neighbour on a grid of height 2.
+        let neighbours: Vec<_> = sparse.neighbours4(corner).collect();
+        assert_eq!(neighbours, [(Point::new(0, 1), &'y')]);
+        let neighbours: Vec<_> = sparse.neighbours8(corner).collect();
+        assert_eq!(
+            neighbours,
+            [(Point::new(0, 1), &'y'), (Point::new(2, 1), &'x')]
+        );
+    }
+}
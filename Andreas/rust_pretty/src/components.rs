@@ -0,0 +1,129 @@
+use leptos::ev::Event;
+use leptos::html::Canvas;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos::wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{CanvasRenderingContext2d, HtmlInputElement};
+
+pub(crate) const BUTTON: &str =
+    "rounded px-3 py-2 m-1 border-b-4 border-l-2 shadow-lg bg-blue-700 border-blue-800 text-white";
+
+/// Cells of a grid drawn in one colour.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Layer {
+    pub colour: &'static str,
+    pub cells: Vec<(i32, i32)>,
+}
+
+impl Layer {
+    pub fn new(colour: &'static str, cells: impl IntoIterator<Item = (i32, i32)>) -> Layer {
+        Layer {
+            colour,
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+/// A `width` x `height` grid on a canvas. The layers are drawn in order, so later layers cover
+/// earlier ones, and the canvas is redrawn whenever one of the signals changes.
+#[component]
+pub(crate) fn GridCanvas(
+    #[prop(into)] width: Signal<i32>,
+    #[prop(into)] height: Signal<i32>,
+    #[prop(into)] layers: Signal<Vec<Layer>>,
+    #[prop(default = 5.0)] cell_size: f64,
+    #[prop(default = "#1e3a8a")] background: &'static str,
+) -> impl IntoView {
+    let canvas = NodeRef::<Canvas>::new();
+    Effect::new(move |_| {
+        let Some(canvas) = canvas.get() else {
+            return;
+        };
+        let (width, height) = (
+            width.get() as f64 * cell_size,
+            height.get() as f64 * cell_size,
+        );
+        // Resizing clears the canvas, so it is done here right before drawing.
+        canvas.set_width(width as u32);
+        canvas.set_height(height as u32);
+        let context: CanvasRenderingContext2d = canvas
+            .get_context("2d")
+            .ok()
+            .flatten()
+            .and_then(|context| context.dyn_into().ok())
+            .expect("canvas has a 2d context");
+        context.set_fill_style_str(background);
+        context.fill_rect(0.0, 0.0, width, height);
+        layers.with(|layers| {
+            for layer in layers {
+                context.set_fill_style_str(layer.colour);
+                for &(x, y) in &layer.cells {
+                    context.fill_rect(
+                        x as f64 * cell_size,
+                        y as f64 * cell_size,
+                        cell_size,
+                        cell_size,
+                    );
+                }
+            }
+        });
+    });
+    view! { <canvas node_ref=canvas class="m-2 shadow-lg"/> }
+}
+
+/// A text area to paste a puzzle input into and a file picker to upload one. Both write the
+/// input into `input`, `example` adds a button that loads the puzzle's example. A file that cannot
+/// be read is reported below the picker until the input changes again.
+#[component]
+pub(crate) fn InputPicker(
+    input: RwSignal<String>,
+    #[prop(optional)] example: Option<&'static str>,
+) -> impl IntoView {
+    let read_error = RwSignal::new(None::<String>);
+    Effect::new(move |_| {
+        input.track();
+        read_error.set(None);
+    });
+    let load_file = move |event: Event| {
+        let picker: HtmlInputElement = event_target(&event);
+        let Some(file) = picker.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            match JsFuture::from(file.text()).await {
+                Ok(text) => input.set(text.as_string().unwrap_or_default()),
+                Err(err) => read_error.set(Some(format!("cannot read {}: {err:?}", file.name()))),
+            }
+        });
+    };
+
+    view! {
+        <div class="flex flex-col m-2 w-full max-w-xl">
+            <textarea
+                class="text-black font-mono text-xs h-32 p-1"
+                placeholder="Paste your puzzle input here"
+                prop:value=input
+                on:input=move |event| input.set(event_target_value(&event))
+            />
+            <div class="flex flex-row flex-wrap items-center">
+                <input type="file" accept=".txt,text/plain" class="m-1" on:change=load_file/>
+                {example
+                    .map(|example| {
+                        view! {
+                            <button class=BUTTON on:click=move |_| input.set(example.to_string())>
+                                "Example"
+                            </button>
+                        }
+                    })}
+            </div>
+            {move || read_error.get().map(|message| view! { <InputError message/> })}
+        </div>
+    }
+}
+
+/// The error of an input that could not be parsed, shown instead of the visualization.
+#[component]
+pub(crate) fn InputError(message: String) -> impl IntoView {
+    view! { <pre class="m-2 p-2 bg-red-900 text-red-100 whitespace-pre-wrap">{message}</pre> }
+}
@@ -0,0 +1,194 @@
+use crate::components::{GridCanvas, InputError, InputPicker, Layer, BUTTON};
+use aoc_common::path::bfs;
+use aoc_common::{parse_number, split_once, ParseError};
+use leptos::prelude::*;
+use std::collections::HashSet;
+
+type Pos = (i32, i32);
+
+/// Width and height of the memory space of the real inputs, the largest there is.
+const MAX_SIZE: i32 = 71;
+
+fn parse_input(file_contents: &str) -> Result<Vec<Pos>, ParseError> {
+    file_contents
+        .lines()
+        .map(|line| {
+            let (x, y) = split_once(file_contents, line, ",")?;
+            let coordinate = |number| match parse_number(file_contents, number)? {
+                value @ 0..MAX_SIZE => Ok(value),
+                _ => Err(ParseError::at(
+                    file_contents,
+                    number,
+                    format!("a coordinate from 0 to {}", MAX_SIZE - 1),
+                )),
+            };
+            Ok((coordinate(x)?, coordinate(y)?))
+        })
+        .collect()
+}
+
+/// The memory space is 71 bytes wide for real inputs and 7 for the example.
+fn memory_size(bytes: &[Pos]) -> i32 {
+    bytes.iter().map(|&(x, y)| x.max(y) + 1).max().unwrap_or(0)
+}
+
+fn shortest_path(corrupted_bytes: &[Pos], size: i32) -> Option<Vec<Pos>> {
+    let corrupted: HashSet<_> = corrupted_bytes.iter().copied().collect();
+    let corrupted = &corrupted;
+    let paths = bfs((0, 0), move |&(x, y)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(move |&(x, y)| {
+                (0..size).contains(&x) && (0..size).contains(&y) && !corrupted.contains(&(x, y))
+            })
+    });
+    paths.path_to(&(size - 1, size - 1))
+}
+
+/// Number of fallen bytes after which the exit cannot be reached anymore (part 2).
+fn first_blocking_byte(bytes: &[Pos], size: i32) -> Option<usize> {
+    if shortest_path(bytes, size).is_some() {
+        return None;
+    }
+    // Binary search, the exit is reachable after `reachable` bytes and cut off after `blocked`.
+    let (mut reachable, mut blocked) = (0, bytes.len());
+    while blocked - reachable > 1 {
+        let fallen = (reachable + blocked) / 2;
+        if shortest_path(&bytes[..fallen], size).is_some() {
+            reachable = fallen;
+        } else {
+            blocked = fallen;
+        }
+    }
+    Some(blocked)
+}
+
+#[component]
+fn MemorySpace(bytes: Vec<Pos>) -> impl IntoView {
+    let size = memory_size(&bytes);
+    let default_fallen = if size > 7 { 1024 } else { 12 };
+    let bytes = StoredValue::new(bytes);
+    let byte_count = bytes.with_value(Vec::len);
+    let fallen = RwSignal::new(byte_count.min(default_fallen));
+    let path =
+        Memo::new(move |_| bytes.with_value(|bytes| shortest_path(&bytes[..fallen.get()], size)));
+
+    let layers = Signal::derive(move || {
+        bytes.with_value(|bytes| {
+            let fallen = &bytes[..fallen.get()];
+            vec![
+                Layer::new("#cbd5e1", fallen.iter().copied()),
+                Layer::new("#fbbf24", path.get().into_iter().flatten()),
+                Layer::new("#dc2626", fallen.last().copied()),
+            ]
+        })
+    });
+    let status = move || match path.get() {
+        Some(path) => format!("Shortest path: {} steps", path.len() - 1),
+        None => bytes.with_value(|bytes| match bytes[..fallen.get()].last() {
+            Some((x, y)) => format!("The exit is cut off since byte {x},{y} fell"),
+            None => "The exit cannot be reached".to_string(),
+        }),
+    };
+    let jump_to_blocking_byte = move |_| {
+        if let Some(blocking) = bytes.with_value(|bytes| first_blocking_byte(bytes, size)) {
+            fallen.set(blocking);
+        }
+    };
+
+    view! {
+        <div class="flex flex-row flex-wrap items-center m-2">
+            <label class="m-1">
+                "Fallen bytes "
+                <input
+                    type="range"
+                    min="0"
+                    max=byte_count
+                    prop:value=move || fallen.get().to_string()
+                    on:input=move |event| {
+                        fallen.set(event_target_value(&event).parse().unwrap_or(0))
+                    }
+                />
+                {move || format!(" {}", fallen.get())}
+            </label>
+            <button class=BUTTON on:click=jump_to_blocking_byte>
+                "First blocking byte"
+            </button>
+        </div>
+        <GridCanvas width=size height=size layers cell_size=8.0/>
+        <p class="m-1">{status}</p>
+    }
+}
+
+#[component]
+pub(crate) fn Day18() -> impl IntoView {
+    let input = RwSignal::new(String::new());
+    let bytes =
+        Memo::new(move |_| input.with(|input| parse_input(input).map_err(|err| err.to_string())));
+
+    view! {
+        <h1 class="text-2xl m-2">"Day 18: RAM Run"</h1>
+        <InputPicker input example=EXAMPLE/>
+        {move || match bytes.get() {
+            Err(message) => view! { <InputError message/> }.into_any(),
+            Ok(bytes) if bytes.is_empty() => {
+                view! { <p class="m-2">"Paste or upload your input to start."</p> }.into_any()
+            }
+            Ok(bytes) => view! { <MemorySpace bytes/> }.into_any(),
+        }}
+    }
+}
+
+const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let bytes = parse_input(EXAMPLE).unwrap();
+        assert_eq!(memory_size(&bytes), 7);
+        assert_eq!(
+            shortest_path(&bytes[..12], 7).map(|path| path.len() - 1),
+            Some(22)
+        );
+        assert_eq!(bytes[first_blocking_byte(&bytes, 7).unwrap() - 1], (6, 1));
+    }
+
+    #[test]
+    fn rejects_bytes_outside_of_the_memory_space() {
+        let err = parse_input("1,2\n-5,-5\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:1: expected a coordinate from 0 to 70, found \"-5\""
+        );
+        assert!(parse_input("71,0\n").is_err());
+    }
+}
@@ -1,12 +1,10 @@
 #![allow(dead_code)]
 
-use itertools::Itertools;
+use crate::components::{GridCanvas, InputError, InputPicker, Layer, BUTTON};
+use aoc_common::{parse_chars, ParseError};
 use leptos::prelude::*;
 use std::collections::HashSet;
-use std::iter::Iterator;
-use std::thread;
 use std::time::Duration;
-use leptos::task::spawn_local;
 
 #[derive(Debug, Eq, Hash, PartialEq, Copy, Clone)]
 struct Pos(i32, i32);
@@ -22,7 +20,7 @@ impl Pos {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 struct MapBase {
     width: i32,
     height: i32,
@@ -58,7 +56,7 @@ impl Direction {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 struct MapState {
     guard_position: Pos,
     guard_direction: Direction,
@@ -78,89 +76,279 @@ impl MapState {
     }
 }
 
-fn parse_input() -> (MapBase, MapState) {
+fn parse_input(file_contents: &str) -> Result<(MapBase, MapState), ParseError> {
     let mut base = MapBase {
-        width: INPUT.lines().next().unwrap().chars().count() as i32,
-        height: INPUT.lines().count() as i32,
+        width: file_contents
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::at(file_contents, "", "a map"))?
+            .chars()
+            .count() as i32,
+        height: file_contents.lines().count() as i32,
         blockades: HashSet::new(),
     };
     let mut guard_position = None;
-    let mut guard_direction = None;
-    for (i, c) in INPUT.chars().filter(|c| *c != '\n').enumerate() {
-        let pos = Pos(i as i32 % base.width, i as i32 / base.height);
-        match c {
-            '.' => {}
-            '#' => {
-                base.blockades.insert(pos);
-            }
-            '^' => {
-                guard_position = Some(pos);
-                guard_direction = Some(Direction::Up);
-            }
-            _ => {
-                unreachable!();
+    for (y, line) in file_contents.lines().enumerate() {
+        if line.chars().count() as i32 != base.width {
+            return Err(ParseError::at(
+                file_contents,
+                line,
+                format!("a row of {} tiles", base.width),
+            ));
+        }
+        let tiles = parse_chars(file_contents, line, "'.', '#' or '^'", |c| {
+            matches!(c, '.' | '#' | '^').then_some(c)
+        })?;
+        for (x, c) in tiles.into_iter().enumerate() {
+            let pos = Pos(x as i32, y as i32);
+            match c {
+                '#' => {
+                    base.blockades.insert(pos);
+                }
+                '^' => {
+                    guard_position = Some(pos);
+                }
+                _ => {}
             }
         }
     }
-    (
+    let guard_position =
+        guard_position.ok_or_else(|| ParseError::at(file_contents, "", "a guard '^'"))?;
+    Ok((
         base,
         MapState {
-            guard_position: guard_position.unwrap(),
-            guard_direction: guard_direction.unwrap(),
+            guard_position,
+            guard_direction: Direction::Up,
             visited: HashSet::new(),
         },
-    )
+    ))
 }
 
-pub(crate) fn main() {
-    let (base, mut state) = parse_input();
-    while !base.is_out_of_map(state.guard_position) {
-        state.step(&base);
-    }
-    println!(
-        "Star 1: {}",
-        state
+fn visited_positions(state: &MapState) -> HashSet<Pos> {
+    state.visited.iter().map(|(p, _)| *p).collect()
+}
+
+fn is_loop(base: &MapBase, mut state: MapState) -> bool {
+    loop {
+        state.step(base);
+        if base.is_out_of_map(state.guard_position) {
+            return false;
+        }
+        if state
             .visited
-            .into_iter()
-            .map(|(p, _)| { p })
-            .unique()
-            .count()
-    );
+            .contains(&(state.guard_position, state.guard_direction))
+        {
+            return true;
+        }
+    }
+}
+
+/// Positions where a new obstacle makes the guard walk in a loop (part 2). Only positions on the
+/// guard's original path can change the walk, so only those are tried.
+fn loop_obstacles(base: &MapBase, state: &MapState) -> HashSet<Pos> {
+    let mut walk = state.clone();
+    while !base.is_out_of_map(walk.guard_position) {
+        walk.step(base);
+    }
+    let mut base = base.clone();
+    visited_positions(&walk)
+        .into_iter()
+        .filter(|&pos| pos != state.guard_position)
+        .filter(|&pos| {
+            base.blockades.insert(pos);
+            let result = is_loop(&base, state.clone());
+            base.blockades.remove(&pos);
+            result
+        })
+        .collect()
+}
+
+const TICK: Duration = Duration::from_millis(30);
+const SPEEDS: [u32; 5] = [1, 5, 20, 100, 500];
+
+fn cells(positions: impl IntoIterator<Item = Pos>) -> impl Iterator<Item = (i32, i32)> {
+    positions.into_iter().map(|Pos(x, y)| (x, y))
 }
 
 #[component]
-pub(crate) fn App() -> impl IntoView {
-    let (value, set_value) = signal(0);
+fn Lab(base: MapBase, initial_state: MapState) -> impl IntoView {
+    let (width, height) = (base.width, base.height);
+    let base = StoredValue::new(base);
+    let initial_state = StoredValue::new(initial_state);
+    let state = RwSignal::new(initial_state.get_value());
+    let playing = RwSignal::new(false);
+    let speed = RwSignal::new(0);
+    let show_obstacles = RwSignal::new(false);
+    // Finding the loop obstacles takes a while, so it is only done once they are asked for.
+    let obstacles = StoredValue::new(None::<HashSet<Pos>>);
+
+    let finished = Memo::new(move |_| {
+        base.with_value(|base| state.with(|state| base.is_out_of_map(state.guard_position)))
+    });
+    let visited = Memo::new(move |_| state.with(visited_positions));
+
+    let step = move |steps: u32| {
+        state.update(|state| {
+            base.with_value(|base| {
+                for _ in 0..steps {
+                    if base.is_out_of_map(state.guard_position) {
+                        break;
+                    }
+                    state.step(base);
+                }
+            })
+        });
+        if finished.get_untracked() {
+            playing.set(false);
+        }
+    };
 
-    let x = spawn_local(async {
-        for i in 1..10 {
-            leptos::logging::log!("hi number {i} from the spawned thread!");
+    Effect::new(move |handle: Option<Option<IntervalHandle>>| {
+        if let Some(Some(handle)) = handle {
+            handle.clear();
         }
+        let steps = SPEEDS[speed.get()];
+        playing
+            .get()
+            .then(|| set_interval_with_handle(move || step(steps), TICK).ok())
+            .flatten()
+    });
+
+    // Only obstacles on the part of the path the guard has already walked are shown, so they
+    // show up while the guard walks.
+    let passed_obstacles = Memo::new(move |_| {
+        if !show_obstacles.get() {
+            return Vec::new();
+        }
+        obstacles.update_value(|obstacles| {
+            obstacles.get_or_insert_with(|| {
+                base.with_value(|base| {
+                    initial_state.with_value(|state| loop_obstacles(base, state))
+                })
+            });
+        });
+        visited.with(|visited| {
+            obstacles.with_value(|obstacles| {
+                obstacles
+                    .iter()
+                    .flatten()
+                    .filter(|pos| visited.contains(pos))
+                    .copied()
+                    .collect()
+            })
+        })
+    });
+
+    let layers = Signal::derive(move || {
+        let guard = state.with(|state| state.guard_position);
+        vec![
+            Layer::new(
+                "#fbbf24",
+                visited.with(|visited| cells(visited.iter().copied()).collect::<Vec<_>>()),
+            ),
+            Layer::new(
+                "#cbd5e1",
+                base.with_value(|base| cells(base.blockades.iter().copied()).collect::<Vec<_>>()),
+            ),
+            Layer::new("#dc2626", cells(passed_obstacles.get())),
+            Layer::new("#22d3ee", cells([guard])),
+        ]
     });
 
-    // thanks to https://tailwindcomponents.com/component/blue-buttons-example for the showcase layout
     view! {
-        <h1>Leptos + Tailwindcss</h1>
-        <main>
-            <div class="bg-gradient-to-tl from-blue-800 to-blue-500 text-white font-mono flex flex-col min-h-screen">
-                <div class="flex flex-row-reverse flex-wrap m-auto">
-                    <button on:click=move |_| set_value.update(|value| *value += 1) class="rounded px-3 py-2 m-1 border-b-4 border-l-2 shadow-lg bg-blue-700 border-blue-800 text-white">
-                        "+"
-                    </button>
-                    <button class="rounded px-3 py-2 m-1 border-b-4 border-l-2 shadow-lg bg-blue-800 border-blue-900 text-white">
-                        {value}
-                    </button>
-                    <button
-                        on:click=move |_| set_value.update(|value| *value -= 1)
-                        class="rounded px-3 py-2 m-1 border-b-4 border-l-2 shadow-lg bg-blue-700 border-blue-800 text-white"
-                        class:invisible=move || {value.get() < 1}
-                    >
-                        "-"
-                    </button>
-                </div>
-            </div>
-        </main>
+        <div class="flex flex-row flex-wrap items-center m-2">
+            <button class=BUTTON on:click=move |_| playing.update(|playing| *playing = !*playing) disabled=finished>
+                {move || if playing.get() { "Pause" } else { "Play" }}
+            </button>
+            <button class=BUTTON on:click=move |_| step(1) disabled=finished>
+                "Step"
+            </button>
+            <button class=BUTTON on:click=move |_| {
+                playing.set(false);
+                state.set(initial_state.get_value());
+            }>
+                "Reset"
+            </button>
+            <label class="m-1">
+                "Speed "
+                <input
+                    type="range"
+                    min="0"
+                    max=SPEEDS.len() - 1
+                    prop:value=move || speed.get().to_string()
+                    on:input=move |event| speed.set(event_target_value(&event).parse().unwrap_or(0))
+                />
+                {move || format!(" {} steps/tick", SPEEDS[speed.get()])}
+            </label>
+            <label class="m-1">
+                <input
+                    type="checkbox"
+                    prop:checked=show_obstacles
+                    on:change=move |event| show_obstacles.set(event_target_checked(&event))
+                />
+                " Loop obstacles (part 2)"
+            </label>
+        </div>
+        <GridCanvas width height layers/>
+        <p class="m-1">{move || format!("Visited positions: {}", visited.with(HashSet::len))}</p>
+        <p class="m-1">
+            {move || show_obstacles.get().then(|| format!("Loop obstacles passed: {}", passed_obstacles.with(Vec::len)))}
+        </p>
+        <p class="m-1">{move || finished.get().then_some("The guard has left the lab.")}</p>
     }
 }
 
-const INPUT: &str = include_str!("../day6_input.txt");
\ No newline at end of file
+#[component]
+pub(crate) fn Day6() -> impl IntoView {
+    let input = RwSignal::new(String::new());
+    let parsed =
+        Memo::new(move |_| input.with(|input| parse_input(input).map_err(|err| err.to_string())));
+
+    view! {
+        <h1 class="text-2xl m-2">"Day 6: Guard Gallivant"</h1>
+        <InputPicker input example=EXAMPLE/>
+        {move || {
+            if input.with(|input| input.trim().is_empty()) {
+                return view! { <p class="m-2">"Paste or upload your input to start."</p> }.into_any();
+            }
+            match parsed.get() {
+                Err(message) => view! { <InputError message/> }.into_any(),
+                Ok((base, initial_state)) => view! { <Lab base initial_state/> }.into_any(),
+            }
+        }}
+    }
+}
+
+const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loop_obstacles_example() {
+        let (base, state) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(loop_obstacles(&base, &state).len(), 6);
+    }
+
+    #[test]
+    fn parse_error_instead_of_panic() {
+        let err = parse_input("..#\n.x^\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:2: expected '.', '#' or '^', found \"x\""
+        );
+        assert!(parse_input("...\n...\n").is_err());
+    }
+}
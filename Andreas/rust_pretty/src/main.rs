@@ -1,8 +1,79 @@
+mod components;
+mod day18;
 mod day6;
 
 use leptos::mount::mount_to_body;
 use leptos::prelude::*;
+use leptos_router::components::{Route, Router, Routes, A};
+use leptos_router::path;
+
+/// Days with a visualizer: number, puzzle title and what is shown.
+const DAYS: &[(u8, &str, &str)] = &[
+    (
+        6,
+        "Guard Gallivant",
+        "The guard walking through the lab, with the obstacles that trap it in a loop.",
+    ),
+    (
+        18,
+        "RAM Run",
+        "Bytes falling into memory and the shortest way out between them.",
+    ),
+];
+
+#[component]
+fn Home() -> impl IntoView {
+    view! {
+        <h1 class="text-2xl m-2">"Advent of Code 2024"</h1>
+        <ul class="m-2">
+            {DAYS
+                .iter()
+                .map(|&(day, title, description)| {
+                    view! {
+                        <li class="m-2">
+                            <A href=format!("/day{day}")>
+                                <span class="underline">{format!("Day {day}: {title}")}</span>
+                            </A>
+                            <p class="text-sm">{description}</p>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    // thanks to https://tailwindcomponents.com/component/blue-buttons-example for the showcase layout
+    view! {
+        <Router>
+            <main class="bg-gradient-to-tl from-blue-800 to-blue-500 text-white font-mono flex flex-col items-center min-h-screen p-4">
+                <nav class="flex flex-row flex-wrap">
+                    <A href="/">
+                        <span class="m-2 underline">"Home"</span>
+                    </A>
+                    {DAYS
+                        .iter()
+                        .map(|&(day, _, _)| {
+                            view! {
+                                <A href=format!("/day{day}")>
+                                    <span class="m-2 underline">{format!("Day {day}")}</span>
+                                </A>
+                            }
+                        })
+                        .collect_view()}
+                </nav>
+                <Routes fallback=|| view! { <p class="m-2">"There is no visualizer for this page."</p> }>
+                    <Route path=path!("/") view=Home/>
+                    <Route path=path!("/day6") view=day6::Day6/>
+                    <Route path=path!("/day18") view=day18::Day18/>
+                </Routes>
+            </main>
+        </Router>
+    }
+}
 
 fn main() {
-    mount_to_body(day6::App);
+    mount_to_body(App);
 }
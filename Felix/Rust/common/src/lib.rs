@@ -0,0 +1,207 @@
+use aoc_common::region::Regions;
+use aoc_common::render::{Cell, Frame};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().turn_right().turn_right()
+    }
+
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+}
+
+/// Moves one cell from (i, j) into `direction`, `None` if that leaves a rows x cols grid.
+pub fn step(
+    i: usize,
+    j: usize,
+    rows: usize,
+    cols: usize,
+    direction: &Direction,
+) -> Option<(usize, usize)> {
+    match direction {
+        Direction::Up => {
+            if i > 0 {
+                Some((i - 1, j))
+            } else {
+                None
+            }
+        }
+        Direction::Down => {
+            if i < rows - 1 {
+                Some((i + 1, j))
+            } else {
+                None
+            }
+        }
+        Direction::Left => {
+            if j > 0 {
+                Some((i, j - 1))
+            } else {
+                None
+            }
+        }
+        Direction::Right => {
+            if j < cols - 1 {
+                Some((i, j + 1))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// A `width` x `height` grid whose edges wrap around: leaving it on one side enters it again on
+/// the opposite side.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Torus {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Torus {
+    /// Panics unless both sides are at least 1, an empty grid has nothing to wrap around to.
+    pub fn new(width: i32, height: i32) -> Self {
+        assert!(
+            width > 0 && height > 0,
+            "a torus needs a width and height of at least 1, not {width} x {height}"
+        );
+        Self { width, height }
+    }
+
+    /// Moves (x, y) by (dx, dy), wrapping around the edges as often as needed.
+    pub fn step(&self, (x, y): (i32, i32), (dx, dy): (i32, i32)) -> (i32, i32) {
+        (
+            (x + dx.rem_euclid(self.width)).rem_euclid(self.width),
+            (y + dy.rem_euclid(self.height)).rem_euclid(self.height),
+        )
+    }
+}
+
+/// Position of the first cell equal to `target`, row by row.
+pub fn find<T: PartialEq>(grid: &[Vec<T>], target: &T) -> Option<(usize, usize)> {
+    for (i, row) in grid.iter().enumerate() {
+        for (j, cell) in row.iter().enumerate() {
+            if cell == target {
+                return Some((i, j));
+            }
+        }
+    }
+    None
+}
+
+/// Renders a grid of rows for printing, `cell` gives the glyph and colour of a value.
+pub fn frame<T, C: Into<Cell>>(grid: &[Vec<T>], cell: impl Fn(&T) -> C) -> Frame {
+    let cols = grid.first().map_or(0, Vec::len);
+    Frame::new(cols, grid.len(), |j, i| cell(&grid[i][j]))
+}
+
+/// Orthogonally connected regions of equal values in a grid of rows. Region positions are
+/// `(x, y)`, i.e. `(j, i)`.
+pub fn regions<T: PartialEq>(grid: &[Vec<T>]) -> Regions {
+    let cols = grid.first().map_or(0, Vec::len);
+    Regions::of_equal(cols, grid.len(), |(j, i)| &grid[i][j])
+}
+
+/// Orthogonally connected regions of the values in a grid of rows for which `member` holds.
+pub fn regions_where<T>(grid: &[Vec<T>], member: impl Fn(&T) -> bool) -> Regions {
+    let cols = grid.first().map_or(0, Vec::len);
+    Regions::matching(cols, grid.len(), |(j, i)| member(&grid[i][j]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_stays_inside_grid() {
+        assert_eq!(step(0, 0, 2, 3, &Direction::Up), None);
+        assert_eq!(step(0, 0, 2, 3, &Direction::Left), None);
+        assert_eq!(step(0, 0, 2, 3, &Direction::Down), Some((1, 0)));
+        assert_eq!(step(1, 2, 2, 3, &Direction::Down), None);
+        assert_eq!(step(1, 2, 2, 3, &Direction::Right), None);
+        assert_eq!(step(1, 1, 2, 3, &Direction::Right), Some((1, 2)));
+    }
+
+    #[test]
+    fn turns_are_quarter_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_ne!(direction.turn_right(), direction.turn_left());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    }
+
+    #[test]
+    fn find_first_match() {
+        let grid = vec![vec!['.', '#'], vec!['S', '#']];
+        assert_eq!(find(&grid, &'#'), Some((0, 1)));
+        assert_eq!(find(&grid, &'S'), Some((1, 0)));
+        assert_eq!(find(&grid, &'E'), None);
+    }
+
+    #[test]
+    fn frame_of_rows() {
+        let grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let frame = frame(&grid, |n| char::from_digit(*n, 10).unwrap());
+        assert_eq!(frame.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn regions_of_rows() {
+        let grid = vec![vec!['a', 'a', 'b'], vec!['b', 'a', 'b']];
+        let regions = regions(&grid);
+        assert_eq!(regions.regions().len(), 3);
+        assert_eq!(regions.label((1, 1)), regions.label((0, 0)));
+        let bs = regions_where(&grid, |&ch| ch == 'b');
+        assert_eq!(bs.iter().map(|region| region.area()).max(), Some(2));
+    }
+
+    #[test]
+    fn torus_wraps_around() {
+        let torus = Torus::new(11, 7);
+        assert_eq!(torus.step((2, 4), (2, -3)), (4, 1));
+        assert_eq!(torus.step((4, 1), (2, -3)), (6, 5));
+        assert_eq!(torus.step((0, 0), (-1, 7)), (10, 0));
+        assert_eq!(torus.step((3, 3), (11 * 100, -7 * 100)), (3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 1")]
+    fn empty_torus() {
+        Torus::new(0, 7);
+    }
+}
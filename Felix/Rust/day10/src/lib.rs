@@ -0,0 +1,90 @@
+use aoc_common::{parse_grid, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day10;
+
+fn in_bounds(i: isize, j: isize, grid: &[Vec<u32>]) -> bool {
+    i >= 0 && j >= 0 && i < (grid.len() as isize) && j < (grid[0].len() as isize)
+}
+
+fn dfs(i: usize, j: usize, curr: u32, grid: &Vec<Vec<u32>>, ends: &mut Vec<(usize, usize)>) {
+    if grid[i][j] == 9 {
+        ends.push((i, j));
+        return;
+    }
+
+    for (di, dj) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+        let (p, q) = (i as isize + di, j as isize + dj);
+        if in_bounds(p, q, grid) && grid[p as usize][q as usize] == curr + 1 {
+            dfs(p as usize, q as usize, curr + 1, grid, ends);
+        }
+    }
+}
+
+fn trailheads(grid: &Vec<Vec<u32>>, count: &dyn Fn(Vec<(usize, usize)>) -> usize) -> usize {
+    let mut res = 0;
+    for i in 0..grid.len() {
+        for j in 0..grid[0].len() {
+            if grid[i][j] == 0 {
+                let mut ends = Vec::new();
+                dfs(i, j, 0, grid, &mut ends);
+                res += count(ends);
+            }
+        }
+    }
+    res
+}
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+        parse_grid(input, "a height digit", |ch| ch.to_digit(10))
+    }
+
+    fn part1(grid: &Vec<Vec<u32>>) -> usize {
+        trailheads(grid, &|ends| ends.into_iter().unique().count())
+    }
+
+    fn part2(grid: &Vec<Vec<u32>>) -> usize {
+        trailheads(grid, &|ends| ends.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part1(&input), 36);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part2(&input), 81);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day10, "input.txt", "answers.txt");
+    }
+}
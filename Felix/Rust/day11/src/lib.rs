@@ -0,0 +1,87 @@
+use aoc_common::{parse_number, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day11;
+
+fn blink(val: u64, blinks: u64, memo: &mut HashMap<(u64, u64), u64>) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+
+    if memo.contains_key(&(val, blinks)) {
+        return memo[&(val, blinks)];
+    }
+
+    let digits = val.checked_ilog10().unwrap_or(0) + 1;
+    let mut res = 0;
+    if val == 0 {
+        res += blink(1, blinks - 1, memo);
+    } else if digits.is_multiple_of(2) {
+        let div = 10_u64.pow(digits / 2);
+        res += blink(val / div, blinks - 1, memo);
+        res += blink(val % div, blinks - 1, memo);
+    } else {
+        res += blink(2024 * val, blinks - 1, memo);
+    }
+
+    memo.insert((val, blinks), res);
+
+    res
+}
+
+fn count_stones(input: &[u64], blinks: u64) -> u64 {
+    let mut memo: HashMap<(u64, u64), u64> = HashMap::new();
+    input
+        .iter()
+        .fold(0, |acc, &val| acc + blink(val, blinks, &mut memo))
+}
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        input
+            .split_whitespace()
+            .map(|s| parse_number(input, s))
+            .collect()
+    }
+
+    fn part1(input: &Vec<u64>) -> u64 {
+        count_stones(input, 25)
+    }
+
+    fn part2(input: &Vec<u64>) -> u64 {
+        count_stones(input, 75)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+125 17
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part1(&input), 55312);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part2(&input), 65601038650482);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day11, "input.txt", "answers.txt");
+    }
+}
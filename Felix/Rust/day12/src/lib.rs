@@ -0,0 +1,95 @@
+use aoc_common::{parse_grid, ParseError, Solution};
+use common::regions;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse_grid(input, "a plant letter", |ch| {
+            ch.is_ascii_uppercase().then_some(ch)
+        })
+    }
+
+    fn part1(grid: &Vec<Vec<char>>) -> u32 {
+        regions(grid)
+            .iter()
+            .fold(0, |acc, plot| acc + plot.area() * plot.perimeter()) as u32
+    }
+
+    fn part2(grid: &Vec<Vec<char>>) -> u32 {
+        regions(grid)
+            .iter()
+            .fold(0, |acc, plot| acc + plot.area() * plot.corners()) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    const E_SHAPED_EXAMPLE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const NESTED_EXAMPLE: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part1(&input), 1930);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 1206);
+    }
+
+    #[test]
+    fn part2_e_shaped_example() {
+        let input = Day12::parse(E_SHAPED_EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 236);
+    }
+
+    #[test]
+    fn part2_nested_example() {
+        let input = Day12::parse(NESTED_EXAMPLE).unwrap();
+        assert_eq!(Day12::part2(&input), 368);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day12, "input.txt", "answers.txt");
+    }
+}
@@ -0,0 +1,119 @@
+use aoc_common::{ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day13;
+
+#[derive(Debug, Clone)]
+struct Coord(i64, i64);
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    a: Coord,
+    b: Coord,
+    goal: Coord,
+}
+
+impl Game {
+    fn new(a: Coord, b: Coord, goal: Coord) -> Self {
+        Self { a, b, goal }
+    }
+
+    fn solve(&self) -> i64 {
+        let det = self.a.0 * self.b.1 - self.a.1 * self.b.0;
+        let m = (self.b.1 * self.goal.0 - self.b.0 * self.goal.1) / det;
+        let n = (-self.a.1 * self.goal.0 + self.a.0 * self.goal.1) / det;
+        if m * self.a.0 + n * self.b.0 == self.goal.0 && m * self.a.1 + n * self.b.1 == self.goal.1
+        {
+            m * 3 + n
+        } else {
+            0
+        }
+    }
+}
+
+fn parse_line(input: &str, s: &str) -> Result<(i64, i64), ParseError> {
+    s.split([',', '+', '='])
+        .flat_map(|substr| substr.parse::<i64>())
+        .collect_tuple()
+        .ok_or_else(|| ParseError::at(input, s, "a line with two numbers"))
+}
+
+fn parse(input: &str, data: &Vec<&str>) -> Result<Vec<Game>, ParseError> {
+    if !(data.len() + 1).is_multiple_of(4) {
+        return Err(ParseError::at(input, "", "a complete claw machine"));
+    }
+    data.iter()
+        .chain([""].iter())
+        .tuples()
+        .map(|(a, b, goal, _)| {
+            let (dx1, dy1) = parse_line(input, a)?;
+            let (dx2, dy2) = parse_line(input, b)?;
+            let (x, y) = parse_line(input, goal)?;
+            Ok(Game::new(Coord(dx1, dy1), Coord(dx2, dy2), Coord(x, y)))
+        })
+        .collect()
+}
+
+impl Solution for Day13 {
+    type Input = Vec<Game>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse(input, &input.lines().collect_vec())
+    }
+
+    fn part1(games: &Vec<Game>) -> i64 {
+        games.iter().fold(0, |acc, game| acc + game.solve())
+    }
+
+    fn part2(games: &Vec<Game>) -> i64 {
+        games.iter().fold(0, |acc, game| {
+            let goal = Coord(game.goal.0 + 10000000000000, game.goal.1 + 10000000000000);
+            acc + Game::new(game.a.clone(), game.b.clone(), goal).solve()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part1(&input), 480);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day13::parse(EXAMPLE).unwrap();
+        assert_eq!(Day13::part2(&input), 875318608908);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day13, "input.txt", "answers.txt");
+    }
+}
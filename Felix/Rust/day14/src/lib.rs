@@ -0,0 +1,204 @@
+use aoc_common::render::{Cell, Rgb};
+use aoc_common::{ParseError, Solution};
+use common::{frame, regions_where, Torus};
+use itertools::Itertools;
+
+pub struct Day14;
+
+#[derive(Debug, Clone)]
+struct Vec2 {
+    x: i32,
+    y: i32,
+}
+
+impl Vec2 {
+    fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    p: Vec2,
+    v: Vec2,
+}
+
+/// The robots and the room they move in. The room is 101 x 103 tiles unless resized, the input
+/// does not say that the example takes place in an 11 x 7 room.
+#[derive(Debug, Clone)]
+pub struct Robots {
+    robots: Vec<Robot>,
+    room: Torus,
+}
+
+impl Robots {
+    /// Moves the robots into `room`, robots outside of it wrap around its edges.
+    pub fn resize(&mut self, room: Torus) {
+        for robot in &mut self.robots {
+            (robot.p.x, robot.p.y) = room.step((robot.p.x, robot.p.y), (0, 0));
+        }
+        self.room = room;
+    }
+}
+
+enum Quadrant {
+    First,
+    Second,
+    Third,
+    Fourth,
+    None,
+}
+
+impl Robot {
+    fn new(p: Vec2, v: Vec2) -> Self {
+        Self { p, v }
+    }
+
+    fn step(&mut self, room: &Torus) {
+        (self.p.x, self.p.y) = room.step((self.p.x, self.p.y), (self.v.x, self.v.y));
+    }
+
+    fn quadrant(&self, room: &Torus) -> Quadrant {
+        let (m, n) = (room.width, room.height);
+        if self.p.x < m / 2 && self.p.y < n / 2 {
+            Quadrant::First
+        } else if self.p.x < m / 2 && self.p.y > n / 2 {
+            Quadrant::Second
+        } else if self.p.x > m / 2 && self.p.y < n / 2 {
+            Quadrant::Third
+        } else if self.p.x > m / 2 && self.p.y > n / 2 {
+            Quadrant::Fourth
+        } else {
+            Quadrant::None
+        }
+    }
+}
+
+fn build_grid(robots: &[Robot], room: &Torus) -> Vec<Vec<char>> {
+    let mut grid = vec![vec!['.'; room.width as usize]; room.height as usize];
+    for robot in robots {
+        grid[robot.p.y as usize][robot.p.x as usize] = 'x';
+    }
+    grid
+}
+
+fn max_island(robots: &[Robot], room: &Torus) -> u32 {
+    regions_where(&build_grid(robots, room), |&ch| ch == 'x')
+        .iter()
+        .map(|island| island.area() as u32)
+        .max()
+        .unwrap_or(0)
+}
+
+#[allow(dead_code)]
+fn print_grid(robots: &[Robot], room: &Torus) {
+    let frame = frame(&build_grid(robots, room), |&ch| match ch {
+        'x' => Cell::coloured(ch, Rgb::GREEN),
+        _ => Cell::new(ch),
+    });
+    print!("{}", frame.ansi());
+}
+
+fn safety_factor(robots: &[Robot], room: &Torus) -> u32 {
+    let mut robots = robots.to_vec();
+    for _ in 0..100 {
+        for robot in &mut robots {
+            robot.step(room);
+        }
+    }
+
+    let (first, second, third, fourth) = robots.iter().fold(
+        (0, 0, 0, 0),
+        |(first, second, third, fourth), robot| match robot.quadrant(room) {
+            Quadrant::First => (first + 1, second, third, fourth),
+            Quadrant::Second => (first, second + 1, third, fourth),
+            Quadrant::Third => (first, second, third + 1, fourth),
+            Quadrant::Fourth => (first, second, third, fourth + 1),
+            Quadrant::None => (first, second, third, fourth),
+        },
+    );
+
+    first * second * third * fourth
+}
+
+impl Solution for Day14 {
+    type Input = Robots;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Robots, ParseError> {
+        let robots = input
+            .lines()
+            .map(|line| {
+                let (px, py, vx, vy) = line
+                    .split(['=', ',', ' '])
+                    .flat_map(|substr| substr.parse::<i32>())
+                    .collect_tuple()
+                    .ok_or_else(|| ParseError::at(input, line, "\"p=x,y v=dx,dy\""))?;
+                Ok(Robot::new(Vec2::new(px, py), Vec2::new(vx, vy)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Robots {
+            robots,
+            room: Torus::new(101, 103),
+        })
+    }
+
+    fn part1(input: &Robots) -> u32 {
+        safety_factor(&input.robots, &input.room)
+    }
+
+    fn part2(input: &Robots) -> u32 {
+        // The robots repeat their positions after width * height steps, the christmas tree is
+        // the configuration with the largest connected island.
+        let room = input.room;
+        let mut robots = input.robots.clone();
+        let mut res = (0, 0);
+        for i in 0..(room.width * room.height) as u32 {
+            let island = max_island(&robots, &room);
+            if island > res.1 {
+                res = (i, island);
+            }
+            for robot in &mut robots {
+                robot.step(&room);
+            }
+        }
+        res.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn part1_example() {
+        let mut robots = Day14::parse(EXAMPLE).unwrap();
+        assert_eq!(robots.room, Torus::new(101, 103));
+        robots.resize(Torus::new(11, 7));
+        assert_eq!(Day14::part1(&robots), 12);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day14, "input.txt", "answers.txt");
+    }
+}
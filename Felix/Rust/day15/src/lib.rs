@@ -1,29 +1,18 @@
-use ::std::fs::File;
 use std::collections::{HashMap, VecDeque};
-use std::io::{self, prelude::*, BufReader};
 
+use aoc_common::render::{Cell, Rgb};
+use aoc_common::{parse_chars, parse_grid, split_once, ParseError, Solution};
+use common::{find, frame, Direction};
 use itertools::Itertools;
 
-#[derive(Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+pub struct Day15;
 
-fn find_start(grid: &Vec<Vec<char>>) -> (usize, usize) {
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            if grid[i][j] == '@' {
-                return (i, j);
-            }
-        }
-    }
-    panic!()
+pub struct Warehouse {
+    grid: Vec<Vec<char>>,
+    directions: Vec<Direction>,
 }
 
-fn step1(pos: (usize, usize), direction: &Direction, grid: &mut Vec<Vec<char>>) -> (usize, usize) {
+fn step1(pos: (usize, usize), direction: &Direction, grid: &mut [Vec<char>]) -> (usize, usize) {
     match direction {
         // Find first non obstacle.
         // If wall:
@@ -91,15 +80,15 @@ fn step1(pos: (usize, usize), direction: &Direction, grid: &mut Vec<Vec<char>>)
 }
 
 fn solve1(directions: &Vec<Direction>, mut grid: Vec<Vec<char>>) -> usize {
-    let mut pos = find_start(&grid);
+    let mut pos = find(&grid, &'@').unwrap();
     for direction in directions {
         pos = step1(pos, direction, &mut grid);
     }
 
     let mut res = 0;
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            if grid[i][j] == 'O' {
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell == 'O' {
                 res += 100 * i + j;
             }
         }
@@ -107,7 +96,7 @@ fn solve1(directions: &Vec<Direction>, mut grid: Vec<Vec<char>>) -> usize {
     res
 }
 
-fn enlarge_grid(grid: &Vec<Vec<char>>) -> Vec<Vec<char>> {
+fn enlarge_grid(grid: &[Vec<char>]) -> Vec<Vec<char>> {
     grid.iter()
         .map(|row| {
             row.iter().fold(Vec::new(), |mut acc, cell| match cell {
@@ -139,7 +128,7 @@ fn enlarge_grid(grid: &Vec<Vec<char>>) -> Vec<Vec<char>> {
 
 fn eval_vert(
     pos: &(usize, usize),
-    grid: &Vec<Vec<char>>,
+    grid: &[Vec<char>],
     f: &dyn Fn(usize) -> usize,
 ) -> Option<Vec<(usize, usize)>> {
     match (grid[pos.0][pos.1], grid[f(pos.0)][pos.1]) {
@@ -157,18 +146,18 @@ fn eval_vert(
 
 fn find_box_cluster(
     pos: &(usize, usize),
-    grid: &Vec<Vec<char>>,
+    grid: &[Vec<char>],
     f: &dyn Fn(usize) -> usize,
 ) -> Option<HashMap<(usize, usize), char>> {
     let mut res = HashMap::new();
 
-    let start = eval_vert(&pos, &grid, f)?;
+    let start = eval_vert(pos, grid, f)?;
     let mut dq = VecDeque::from(start);
     while !dq.is_empty() {
         for _ in 0..dq.len() {
             let (i, j) = dq.pop_front().unwrap();
             res.insert((i, j), grid[i][j]);
-            let next = eval_vert(&(i, j), &grid, f)?;
+            let next = eval_vert(&(i, j), grid, f)?;
             dq.extend(next.iter());
         }
     }
@@ -176,12 +165,12 @@ fn find_box_cluster(
     Some(res)
 }
 
-fn step2(pos: (usize, usize), direction: &Direction, grid: &mut Vec<Vec<char>>) -> (usize, usize) {
+fn step2(pos: (usize, usize), direction: &Direction, grid: &mut [Vec<char>]) -> (usize, usize) {
     match direction {
         Direction::Up => {
             if grid[pos.0 - 1][pos.1] == '[' || grid[pos.0 - 1][pos.1] == ']' {
-                if let Some(cluster) = find_box_cluster(&pos, &grid, &|i| i - 1) {
-                    for ((i, j), _) in &cluster {
+                if let Some(cluster) = find_box_cluster(&pos, grid, &|i| i - 1) {
+                    for (i, j) in cluster.keys() {
                         grid[*i][*j] = '.';
                     }
                     for ((i, j), v) in &cluster {
@@ -203,8 +192,8 @@ fn step2(pos: (usize, usize), direction: &Direction, grid: &mut Vec<Vec<char>>)
         }
         Direction::Down => {
             if grid[pos.0 + 1][pos.1] == '[' || grid[pos.0 + 1][pos.1] == ']' {
-                if let Some(cluster) = find_box_cluster(&pos, &grid, &|i| i + 1) {
-                    for ((i, j), _) in &cluster {
+                if let Some(cluster) = find_box_cluster(&pos, grid, &|i| i + 1) {
+                    for (i, j) in cluster.keys() {
                         grid[*i][*j] = '.';
                     }
                     for ((i, j), v) in &cluster {
@@ -259,32 +248,30 @@ fn step2(pos: (usize, usize), direction: &Direction, grid: &mut Vec<Vec<char>>)
     }
 }
 
-fn print_grid(grid: &Vec<Vec<char>>) {
-    let s = grid
-        .iter()
-        .map(|line| {
-            line.iter().fold("".to_string(), |mut acc, ch| {
-                acc.push(*ch);
-                acc
-            })
-        })
-        .fold("".to_string(), |acc, s| format!("{}\n{}", acc, s));
-    println!("{}", s);
+#[allow(dead_code)]
+fn print_grid(grid: &[Vec<char>]) {
+    let frame = frame(grid, |&ch| match ch {
+        '#' => Cell::coloured(ch, Rgb::GREY),
+        '@' => Cell::coloured(ch, Rgb::RED),
+        'O' | '[' | ']' => Cell::coloured(ch, Rgb::YELLOW),
+        _ => Cell::new(ch),
+    });
+    print!("{}", frame.ansi());
 }
 
-fn solve2(directions: &Vec<Direction>, grid: &Vec<Vec<char>>) -> usize {
-    let mut grid = enlarge_grid(&grid);
+fn solve2(directions: &[Direction], grid: &[Vec<char>]) -> usize {
+    let mut grid = enlarge_grid(grid);
 
-    let mut pos = find_start(&grid);
+    let mut pos = find(&grid, &'@').unwrap();
     for direction in directions {
         pos = step2(pos, direction, &mut grid);
         // print_grid(&grid);
     }
 
     let mut res = 0;
-    for i in 0..grid.len() {
-        for j in 0..grid[0].len() {
-            if grid[i][j] == '[' {
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell == '[' {
                 res += 100 * i + j;
             }
         }
@@ -292,42 +279,117 @@ fn solve2(directions: &Vec<Direction>, grid: &Vec<Vec<char>>) -> usize {
     res
 }
 
-fn main() -> io::Result<()> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
-
-    let grid = reader
-        .lines()
-        .map(|line| line.unwrap().chars().collect_vec())
-        .take_while(|row| !row.is_empty())
-        .collect_vec();
-
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
-
-    let directions = reader
-        .lines()
-        .map(|line| line.unwrap())
-        .skip_while(|line| !line.starts_with(['<', '^', 'v', '>']))
-        .fold(Vec::new(), |mut acc, line| {
-            let next_ds = line
-                .chars()
-                .filter(|d| d != &' ')
-                .map(|d| match d {
-                    '^' => Direction::Up,
-                    'v' => Direction::Down,
-                    '<' => Direction::Left,
-                    '>' => Direction::Right,
-                    _ => panic!(),
-                })
-                .collect_vec();
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Warehouse, ParseError> {
+        let (grid, moves) = split_once(input, input, "\n\n")?;
+        let grid = parse_grid(grid, "a warehouse tile", |ch| {
+            matches!(ch, '#' | '.' | 'O' | '@').then_some(ch)
+        })?;
+
+        let directions = moves.lines().try_fold(Vec::new(), |mut acc, line| {
+            let next_ds = parse_chars(input, line, "'^', 'v', '<' or '>'", Direction::from_arrow)?;
             acc.extend(next_ds);
-            acc
-        });
+            Ok(acc)
+        })?;
+
+        Ok(Warehouse { grid, directions })
+    }
+
+    fn part1(warehouse: &Warehouse) -> usize {
+        solve1(&warehouse.directions, warehouse.grid.clone())
+    }
+
+    fn part2(warehouse: &Warehouse) -> usize {
+        solve2(&warehouse.directions, &warehouse.grid)
+    }
+}
 
-    println!("{:?}", solve1(&directions, grid.clone()));
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("{:?}", solve2(&directions, &grid));
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
 
-    Ok(())
+<^^>>>vv<v>>v<<
+";
+
+    const LARGE_EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    const WIDE_EXAMPLE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+";
+
+    #[test]
+    fn part1_small_example() {
+        let input = Day15::parse(SMALL_EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), 2028);
+    }
+
+    #[test]
+    fn part1_large_example() {
+        let input = Day15::parse(LARGE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part1(&input), 10092);
+    }
+
+    #[test]
+    fn part2_large_example() {
+        let input = Day15::parse(LARGE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), 9021);
+    }
+
+    #[test]
+    fn part2_wide_example() {
+        let input = Day15::parse(WIDE_EXAMPLE).unwrap();
+        assert_eq!(Day15::part2(&input), 618);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day15, "input.txt", "answers.txt");
+    }
 }
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use aoc_common::path::dijkstra;
+use aoc_common::{parse_grid, MaybeAnswer, ParseError, Solution};
+use common::{step, Direction};
+
+pub struct Day16;
+
+type Position = (usize, usize);
+
+/// The position of the only `marker` in the grid. Errors point at the second one, or at the
+/// end of the input if there is none.
+fn find_unique(input: &str, marker: char, expected: &str) -> Result<Position, ParseError> {
+    let mut found = input.lines().enumerate().flat_map(|(i, line)| {
+        line.match_indices(marker)
+            .map(move |(j, token)| ((i, j), token))
+    });
+    match (found.next(), found.next()) {
+        (Some((position, _)), None) => Ok(position),
+        (Some(_), Some((_, second))) => Err(ParseError::at(
+            input,
+            second,
+            format!("exactly one {expected}"),
+        )),
+        (None, _) => Err(ParseError::at(input, &input[input.len()..], expected)),
+    }
+}
+
+/// Lowest score from S to E and the tiles on the paths with that score, `None` if E cannot be
+/// reached. The reindeer starts facing east, a step costs 1 and a turn 1000.
+fn solve(maze: &Maze) -> Option<(u32, HashSet<Position>)> {
+    let grid = &maze.grid;
+    let (rows, cols) = (grid.len(), grid[0].len());
+
+    let paths = dijkstra((maze.start, Direction::Right), |&((i, j), direction)| {
+        [
+            (direction.turn_left(), 1001),
+            (direction.turn_right(), 1001),
+            (direction, 1),
+        ]
+        .into_iter()
+        .filter_map(move |(direction, cost)| {
+            let (p, q) = step(i, j, rows, cols, &direction)?;
+            (grid[p][q] != '#').then_some((((p, q), direction), cost))
+        })
+    });
+
+    let ends: Vec<_> = paths
+        .distances()
+        .iter()
+        .filter(|((position, _), _)| *position == maze.end)
+        .collect();
+    let res = ends.iter().map(|(_, &cost)| cost).min()?;
+    let path = paths
+        .on_shortest_paths(
+            ends.iter()
+                .filter(|(_, &cost)| cost == res)
+                .map(|(&pose, _)| pose),
+        )
+        .into_iter()
+        .map(|(position, _)| position)
+        .collect();
+
+    Some((res, path))
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    grid: Vec<Vec<char>>,
+    start: Position,
+    end: Position,
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type Answer1 = MaybeAnswer<u32>;
+    type Answer2 = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Maze, ParseError> {
+        let input = input.trim_end();
+        let grid = parse_grid(input, "'.', '#', 'S' or 'E'", |ch| {
+            matches!(ch, '.' | '#' | 'S' | 'E').then_some(ch)
+        })?;
+        let start = find_unique(input, 'S', "start tile 'S'")?;
+        let end = find_unique(input, 'E', "end tile 'E'")?;
+        Ok(Maze { grid, start, end })
+    }
+
+    fn part1(maze: &Maze) -> MaybeAnswer<u32> {
+        solve(maze).map(|(score, _)| score).into()
+    }
+
+    fn part2(maze: &Maze) -> MaybeAnswer<usize> {
+        solve(maze).map(|(_, tiles)| tiles.len()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const EXAMPLE2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn part1_example1() {
+        let input = Day16::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day16::part1(&input), MaybeAnswer(Some(7036)));
+    }
+
+    #[test]
+    fn part1_example2() {
+        let input = Day16::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day16::part1(&input), MaybeAnswer(Some(11048)));
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = Day16::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day16::part2(&input), MaybeAnswer(Some(45)));
+    }
+
+    #[test]
+    fn part2_example2() {
+        let input = Day16::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day16::part2(&input), MaybeAnswer(Some(64)));
+    }
+
+    #[test]
+    fn parse_requires_one_start_and_one_end() {
+        let err = Day16::parse("#####\n#S.S#\n#..E#\n#####\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:4: expected exactly one start tile 'S', found \"S\""
+        );
+        let err = Day16::parse("#####\n#S..#\n#####\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:3:6: expected end tile 'E', found end of input"
+        );
+    }
+
+    #[test]
+    fn maze_without_border_walls() {
+        let input = Day16::parse("S.#\n..E\n").unwrap();
+        assert_eq!(Day16::part1(&input), MaybeAnswer(Some(2003)));
+        let input = Day16::parse("S#E\n").unwrap();
+        assert_eq!(Day16::part1(&input).to_string(), "no answer");
+        assert_eq!(Day16::part2(&input).to_string(), "no answer");
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day16, "input.txt", "answers.txt");
+    }
+}
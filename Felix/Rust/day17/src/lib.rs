@@ -0,0 +1,362 @@
+use std::fmt::{self, Display, Formatter};
+
+use aoc_common::{parse_number, split_once, MaybeAnswer, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day17;
+
+/// Steps after which a program is taken to loop forever, the puzzle programs halt after a few
+/// hundred.
+const MAX_STEPS: usize = 1_000_000;
+
+#[derive(Debug, Clone)]
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operand {
+    Literal(u8),
+    Combo(u8),
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Combo(4) => write!(f, "A"),
+            Operand::Combo(5) => write!(f, "B"),
+            Operand::Combo(6) => write!(f, "C"),
+            Operand::Literal(value) | Operand::Combo(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Instruction {
+    Adv(Operand),
+    Bxl(Operand),
+    Bst(Operand),
+    Jnz(Operand),
+    Bxc,
+    Out(Operand),
+    Bdv(Operand),
+    Cdv(Operand),
+}
+
+impl Instruction {
+    fn decode(opcode: u8, operand: u8) -> Self {
+        let (literal, combo) = (Operand::Literal(operand), Operand::Combo(operand));
+        match opcode {
+            0 => Instruction::Adv(combo),
+            1 => Instruction::Bxl(literal),
+            2 => Instruction::Bst(combo),
+            3 => Instruction::Jnz(literal),
+            4 => Instruction::Bxc,
+            5 => Instruction::Out(combo),
+            6 => Instruction::Bdv(combo),
+            7 => Instruction::Cdv(combo),
+            _ => unreachable!("opcodes have 3 bits"),
+        }
+    }
+
+    fn operand(&self) -> Option<Operand> {
+        match self {
+            Instruction::Adv(operand)
+            | Instruction::Bxl(operand)
+            | Instruction::Bst(operand)
+            | Instruction::Jnz(operand)
+            | Instruction::Out(operand)
+            | Instruction::Bdv(operand)
+            | Instruction::Cdv(operand) => Some(*operand),
+            Instruction::Bxc => None,
+        }
+    }
+
+    /// What the instruction does, in pseudo code.
+    fn effect(&self) -> String {
+        match self {
+            Instruction::Adv(operand) => format!("A = A >> {operand}"),
+            Instruction::Bxl(operand) => format!("B = B ^ {operand}"),
+            Instruction::Bst(operand) => format!("B = {operand} % 8"),
+            Instruction::Jnz(operand) => format!("if A != 0 goto {operand}"),
+            Instruction::Bxc => "B = B ^ C".to_string(),
+            Instruction::Out(operand) => format!("out {operand} % 8"),
+            Instruction::Bdv(operand) => format!("B = A >> {operand}"),
+            Instruction::Cdv(operand) => format!("C = A >> {operand}"),
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Adv(operand) => write!(f, "adv {operand}"),
+            Instruction::Bxl(operand) => write!(f, "bxl {operand}"),
+            Instruction::Bst(operand) => write!(f, "bst {operand}"),
+            Instruction::Jnz(operand) => write!(f, "jnz {operand}"),
+            Instruction::Bxc => write!(f, "bxc"),
+            Instruction::Out(operand) => write!(f, "out {operand}"),
+            Instruction::Bdv(operand) => write!(f, "bdv {operand}"),
+            Instruction::Cdv(operand) => write!(f, "cdv {operand}"),
+        }
+    }
+}
+
+impl Computer {
+    fn value(&self, operand: Operand) -> u64 {
+        match operand {
+            Operand::Literal(value) | Operand::Combo(value @ 0..=3) => value as u64,
+            Operand::Combo(4) => self.a,
+            Operand::Combo(5) => self.b,
+            Operand::Combo(6) => self.c,
+            Operand::Combo(value) => {
+                unreachable!("the parser rejects the reserved combo operand {value}")
+            }
+        }
+    }
+
+    fn shift(&self, operand: Operand) -> u64 {
+        u32::try_from(self.value(operand))
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    fn instructions(&self) -> impl Iterator<Item = (usize, Instruction)> + '_ {
+        self.program
+            .iter()
+            .tuples()
+            .enumerate()
+            .map(|(i, (opcode, operand))| (2 * i, Instruction::decode(*opcode, *operand)))
+    }
+
+    /// The output of the program, `None` if it has not halted after `MAX_STEPS` steps.
+    fn run(mut self) -> Option<Vec<u8>> {
+        let mut output = Vec::new();
+        let mut ip = 0;
+        for _ in 0..MAX_STEPS {
+            if ip + 1 >= self.program.len() {
+                return Some(output);
+            }
+            let instruction = Instruction::decode(self.program[ip], self.program[ip + 1]);
+            ip += 2;
+            match instruction {
+                Instruction::Adv(operand) => self.a = self.shift(operand),
+                Instruction::Bxl(operand) => self.b ^= self.value(operand),
+                Instruction::Bst(operand) => self.b = self.value(operand) % 8,
+                Instruction::Jnz(operand) => {
+                    if self.a != 0 {
+                        ip = self.value(operand) as usize;
+                    }
+                }
+                Instruction::Bxc => self.b ^= self.c,
+                Instruction::Out(operand) => output.push((self.value(operand) % 8) as u8),
+                Instruction::Bdv(operand) => self.b = self.shift(operand),
+                Instruction::Cdv(operand) => self.c = self.shift(operand),
+            }
+        }
+        None
+    }
+
+    fn run_with_a(&self, a: u64) -> Option<Vec<u8>> {
+        Computer {
+            a,
+            program: self.program.clone(),
+            ..*self
+        }
+        .run()
+    }
+
+    /// The program as one instruction per line, with its address and effect.
+    pub fn disassemble(&self) -> String {
+        self.instructions()
+            .map(|(address, instruction)| {
+                format!(
+                    "{address:02}  {:<6} {}\n",
+                    instruction.to_string(),
+                    instruction.effect()
+                )
+            })
+            .collect()
+    }
+
+    /// Graphviz graph of the control flow, jumps are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph program {\n    node [shape=box];\n");
+        for (address, instruction) in self.instructions() {
+            dot += &format!("    {address} [label=\"{address:02}  {instruction}\"];\n");
+            if address + 3 < self.program.len() {
+                dot += &format!("    {address} -> {};\n", address + 2);
+            }
+            // Jumps to odd addresses or past the end have no instruction to point at.
+            if let Instruction::Jnz(Operand::Literal(target)) = instruction {
+                if target % 2 == 0 && (target as usize) + 1 < self.program.len() {
+                    dot += &format!("    {address} -> {target} [style=dashed];\n");
+                }
+            }
+        }
+        dot + "}\n"
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type Answer1 = MaybeAnswer<String>;
+    type Answer2 = MaybeAnswer<u64>;
+
+    fn parse(input: &str) -> Result<Computer, ParseError> {
+        let (registers, program) = split_once(input, input, "\n\n")?;
+        let registers = registers
+            .lines()
+            .zip(["A", "B", "C"])
+            .map(|(line, name)| {
+                let (label, value) = split_once(input, line, ": ")?;
+                if label != format!("Register {name}") {
+                    return Err(ParseError::at(input, label, format!("\"Register {name}\"")));
+                }
+                parse_number(input, value)
+            })
+            .collect::<Result<Vec<u64>, _>>()?;
+        let &[a, b, c] = &registers[..] else {
+            return Err(ParseError::at(input, "", "registers A, B and C"));
+        };
+
+        let (label, program) = split_once(input, program.trim_end(), ": ")?;
+        if label != "Program" {
+            return Err(ParseError::at(input, label, "\"Program\""));
+        }
+        let tokens = program.split(',').collect_vec();
+        let program = tokens
+            .iter()
+            .map(|bits| {
+                let value = parse_number(input, bits)?;
+                if value > 7 {
+                    return Err(ParseError::at(input, bits, "a 3-bit number"));
+                }
+                Ok(value)
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        // Combo operand 7 is reserved and does not appear in valid programs.
+        let computer = Computer { a, b, c, program };
+        for (address, instruction) in computer.instructions() {
+            if instruction.operand() == Some(Operand::Combo(7)) {
+                return Err(ParseError::at(
+                    input,
+                    tokens[address + 1],
+                    "a combo operand from 0 to 6",
+                ));
+            }
+        }
+        Ok(computer)
+    }
+
+    /// No answer if the program does not halt.
+    fn part1(computer: &Computer) -> MaybeAnswer<String> {
+        computer
+            .clone()
+            .run()
+            .map(|output| output.iter().join(","))
+            .into()
+    }
+
+    /// Every round of the loop outputs one number from the lowest bits of A and shifts A by 3
+    /// bits, so the last output only depends on the highest 3 bits. A is built 3 bits at a time
+    /// from the end of the program, keeping every candidate that outputs the program's tail.
+    /// No answer for programs of another shape.
+    fn part2(computer: &Computer) -> MaybeAnswer<u64> {
+        let instructions = computer.instructions().map(|(_, i)| i).collect_vec();
+        let shifts = instructions
+            .iter()
+            .filter(|i| matches!(i, Instruction::Adv(_)))
+            .collect_vec();
+        let jnz = Instruction::Jnz(Operand::Literal(0));
+        if shifts != [&Instruction::Adv(Operand::Combo(3))] || instructions.last() != Some(&jnz) {
+            return MaybeAnswer(None);
+        }
+
+        (0..computer.program.len())
+            .rev()
+            .fold(vec![0], |candidates, start| {
+                candidates
+                    .iter()
+                    .flat_map(|a| (0..8).map(move |bits| a << 3 | bits))
+                    .filter(|&a| {
+                        computer.run_with_a(a).as_deref() == Some(&computer.program[start..])
+                    })
+                    .collect()
+            })
+            .into_iter()
+            .min()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+    const EXAMPLE2: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day17::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day17::part1(&input).to_string(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day17::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day17::part2(&input), MaybeAnswer(Some(117440)));
+        let input = Day17::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day17::part2(&input).to_string(), "no answer");
+    }
+
+    #[test]
+    fn malformed_programs() {
+        let err = Day17::parse(&EXAMPLE1.replace("5,4", "5,7")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:5:16: expected a combo operand from 0 to 6, found \"7\""
+        );
+        let input = Day17::parse(&EXAMPLE1.replace("0,1,5,4,3,0", "5,4,3,0")).unwrap();
+        assert_eq!(Day17::part1(&input).to_string(), "no answer");
+        let input = Day17::parse(&EXAMPLE1.replace("3,0", "3,5")).unwrap();
+        assert!(!input.to_dot().contains("dashed"));
+    }
+
+    #[test]
+    fn disassemble_example() {
+        let input = Day17::parse(EXAMPLE2).unwrap();
+        assert_eq!(
+            input.disassemble(),
+            "00  adv 3  A = A >> 3\n02  out A  out A % 8\n04  jnz 0  if A != 0 goto 0\n"
+        );
+        assert!(input.to_dot().contains("    4 -> 0 [style=dashed];\n"));
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day17, "input.txt", "answers.txt");
+    }
+}
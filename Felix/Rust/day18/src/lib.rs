@@ -0,0 +1,130 @@
+use aoc_common::path;
+use aoc_common::{parse_number, split_once, ParseError, Solution};
+use common::{step, Direction};
+
+pub struct Day18;
+
+const SIZE: usize = 71;
+const FALLEN: usize = 1024;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum Cell {
+    Free,
+    Blocked,
+}
+
+fn bfs(start: (usize, usize), grid: &[Vec<Cell>]) -> Option<u32> {
+    let size = grid.len();
+    let paths = path::bfs(start, |&(i, j)| {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| step(i, j, size, size, direction))
+            .filter(|&(p, q)| grid[p][q] != Cell::Blocked)
+    });
+    paths
+        .distance(&(size - 1, size - 1))
+        .map(|steps| steps as u32)
+}
+
+fn build_grid(blocked: &[(usize, usize)], size: usize, fallen: usize) -> Vec<Vec<Cell>> {
+    let mut grid = vec![vec![Cell::Free; size]; size];
+    blocked
+        .iter()
+        .take(fallen)
+        .for_each(|(i, j)| grid[*i][*j] = Cell::Blocked);
+    grid
+}
+
+fn shortest_path(blocked: &[(usize, usize)], size: usize, fallen: usize) -> Option<u32> {
+    bfs((0, 0), &build_grid(blocked, size, fallen))
+}
+
+fn first_blocking_byte(blocked: &[(usize, usize)], size: usize, fallen: usize) -> String {
+    let mut grid = build_grid(blocked, size, fallen);
+
+    let mut it = blocked.iter().skip(fallen);
+    let mut res2 = (0, 0);
+    while bfs((0, 0), &grid).is_some() {
+        if let Some((i, j)) = it.next() {
+            res2 = (*i, *j);
+            grid[*i][*j] = Cell::Blocked;
+        }
+    }
+
+    format!("{},{}", res2.1, res2.0)
+}
+
+impl Solution for Day18 {
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        input.lines().try_fold(Vec::new(), |mut acc, line| {
+            let (j, i) = split_once(input, line, ",")?;
+            acc.push((parse_number(input, i)?, parse_number(input, j)?));
+            Ok(acc)
+        })
+    }
+
+    fn part1(blocked: &Vec<(usize, usize)>) -> u32 {
+        shortest_path(blocked, SIZE, FALLEN).unwrap()
+    }
+
+    fn part2(blocked: &Vec<(usize, usize)>) -> String {
+        first_blocking_byte(blocked, SIZE, FALLEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn part1_example() {
+        let blocked = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(shortest_path(&blocked, 7, 12), Some(22));
+    }
+
+    #[test]
+    fn part2_example() {
+        let blocked = Day18::parse(EXAMPLE).unwrap();
+        assert_eq!(first_blocking_byte(&blocked, 7, 12), "6,1");
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day18, "input.txt", "answers.txt");
+    }
+}
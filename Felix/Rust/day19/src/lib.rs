@@ -0,0 +1,112 @@
+use aoc_common::{parse_chars, split_once, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day19;
+
+pub struct Towels {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+fn is_possible(design: &str, patterns: &Vec<String>) -> bool {
+    let mut dp = vec![false; design.len() + 1];
+    dp[design.len()] = true;
+
+    for i in (0..design.len()).rev() {
+        for pattern in patterns {
+            if i + pattern.len() <= design.len() {
+                dp[i] = dp[i] || dp[i + pattern.len()] && design[i..].starts_with(pattern);
+            }
+        }
+    }
+
+    dp[0]
+}
+
+fn get_combinations(design: &str, patterns: &Vec<String>) -> u64 {
+    let mut dp = vec![0; design.len() + 1];
+    dp[design.len()] = 1;
+
+    for i in (0..design.len()).rev() {
+        for pattern in patterns {
+            if i + pattern.len() <= design.len() && design[i..].starts_with(pattern) {
+                dp[i] += dp[i + pattern.len()]
+            }
+        }
+    }
+
+    dp[0]
+}
+
+impl Solution for Day19 {
+    type Input = Towels;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Towels, ParseError> {
+        let (patterns, designs) = split_once(input, input, "\n\n")?;
+        let stripes = |s| {
+            parse_chars(input, s, "a stripe color", |ch| {
+                "wubrg".contains(ch).then_some(ch)
+            })
+            .map(String::from_iter)
+        };
+
+        let patterns = patterns.split(", ").map(stripes).try_collect()?;
+        let designs = designs.lines().map(stripes).try_collect()?;
+
+        Ok(Towels { patterns, designs })
+    }
+
+    fn part1(towels: &Towels) -> usize {
+        towels
+            .designs
+            .iter()
+            .filter(|design| is_possible(design, &towels.patterns))
+            .count()
+    }
+
+    fn part2(towels: &Towels) -> u64 {
+        towels.designs.iter().fold(0, |acc, design| {
+            acc + get_combinations(design, &towels.patterns)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part1(&input), 6);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day19::parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::part2(&input), 16);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day19, "input.txt", "answers.txt");
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use aoc_common::path;
+use aoc_common::{parse_grid, ParseError, Solution};
+use common::{find, step, Direction};
+
+pub struct Day20;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Cell {
+    Regular,
+    Wall,
+    Start,
+    Finish,
+}
+
+fn bfs(grid: &[Vec<Cell>]) -> Vec<(usize, usize)> {
+    let start = find(grid, &Cell::Start).unwrap();
+    let finish = find(grid, &Cell::Finish).unwrap();
+    let (rows, cols) = (grid.len(), grid[0].len());
+    let paths = path::bfs(start, |&(i, j)| {
+        Direction::ALL
+            .iter()
+            .filter_map(move |direction| step(i, j, rows, cols, direction))
+            .filter(|pos| grid[pos.0][pos.1] != Cell::Wall)
+    });
+    paths.path_to(&finish).unwrap()
+}
+
+fn get_cheats(path: &[(usize, usize)], cheat_duration: usize) -> HashMap<usize, usize> {
+    let mut cheats = HashMap::new();
+    for (i, &from) in path.iter().enumerate() {
+        for (j, &to) in path.iter().enumerate().skip(i + 1) {
+            let dist = from.0.abs_diff(to.0) + from.1.abs_diff(to.1);
+            let steps = j - i;
+            if steps > dist && dist <= cheat_duration {
+                let save = j - i - dist;
+                *cheats.entry(save).or_default() += 1;
+            }
+        }
+    }
+    cheats
+}
+
+fn count_cheats(grid: &[Vec<Cell>], cheat_duration: usize, min_save: usize) -> usize {
+    let path = bfs(grid);
+    let cheats = get_cheats(&path, cheat_duration);
+    cheats
+        .iter()
+        .fold(0, |acc, (k, v)| if *k >= min_save { acc + v } else { acc })
+}
+
+impl Solution for Day20 {
+    type Input = Vec<Vec<Cell>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Cell>>, ParseError> {
+        parse_grid(input, "'.', '#', 'S' or 'E'", |cell| match cell {
+            '.' => Some(Cell::Regular),
+            '#' => Some(Cell::Wall),
+            'S' => Some(Cell::Start),
+            'E' => Some(Cell::Finish),
+            _ => None,
+        })
+    }
+
+    fn part1(grid: &Vec<Vec<Cell>>) -> usize {
+        count_cheats(grid, 2, 100)
+    }
+
+    fn part2(grid: &Vec<Vec<Cell>>) -> usize {
+        count_cheats(grid, 20, 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn part1_example() {
+        let grid = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(count_cheats(&grid, 2, 1), 44);
+        assert_eq!(count_cheats(&grid, 2, 64), 1);
+    }
+
+    #[test]
+    fn part2_example() {
+        let grid = Day20::parse(EXAMPLE).unwrap();
+        assert_eq!(count_cheats(&grid, 20, 50), 285);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day20, "input.txt", "answers.txt");
+    }
+}
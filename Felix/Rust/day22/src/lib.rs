@@ -0,0 +1,131 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::BitXor,
+};
+
+use aoc_common::{parse_number, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day22;
+
+fn mix(secret_number: i64, value: i64) -> i64 {
+    secret_number.bitxor(value)
+}
+
+fn prune(secret_number: i64) -> i64 {
+    secret_number % 16777216
+}
+
+fn evolve(secret_number: i64) -> i64 {
+    let mixer = 64 * secret_number;
+    let secret_number = mix(secret_number, mixer);
+    let secret_number = prune(secret_number);
+    let mixer = secret_number / 32;
+    let secret_number = mix(secret_number, mixer);
+    let secret_number = prune(secret_number);
+    let mixer = 2048 * secret_number;
+    let secret_number = mix(secret_number, mixer);
+
+    prune(secret_number)
+}
+
+fn translate(secret_number: i64) -> i64 {
+    secret_number % 10
+}
+
+impl Solution for Day22 {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_number(input, line))
+            .collect()
+    }
+
+    fn part1(secret_numbers: &Vec<i64>) -> i64 {
+        secret_numbers.iter().fold(0, |acc, secret_number| {
+            let mut secret_number = *secret_number;
+            for _ in 0..2000 {
+                secret_number = evolve(secret_number);
+            }
+            acc + secret_number
+        })
+    }
+
+    fn part2(secret_numbers: &Vec<i64>) -> i64 {
+        secret_numbers
+            .iter()
+            .map(|secret_number| {
+                let mut secret_number = *secret_number;
+                let mut seq = Vec::new();
+                let mut prev = translate(secret_number);
+                for _ in 0..2000 {
+                    secret_number = evolve(secret_number);
+                    let digit = translate(secret_number);
+                    seq.push((digit, digit - prev));
+                    prev = digit;
+                }
+                seq
+            })
+            .fold(
+                HashMap::<(i64, i64, i64, i64), i64>::new(),
+                |mut acc, seq| {
+                    let mut visited = HashSet::new();
+                    seq.iter()
+                        .tuple_windows()
+                        .for_each(|(first, second, third, fourth)| {
+                            let key = (first.1, second.1, third.1, fourth.1);
+                            if !visited.contains(&key) {
+                                visited.insert(key);
+                                *acc.entry(key).or_default() += fourth.0;
+                            }
+                        });
+                    acc
+                },
+            )
+            .iter()
+            .fold(0, |acc, (_, v)| acc.max(*v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+1
+10
+100
+2024
+";
+
+    const EXAMPLE2: &str = "\
+1
+2
+3
+2024
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day22::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day22::part1(&input), 37327623);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day22::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day22::part2(&input), 23);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day22, "input.txt", "answers.txt");
+    }
+}
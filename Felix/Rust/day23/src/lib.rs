@@ -0,0 +1,126 @@
+use std::{collections::HashMap, collections::HashSet};
+
+use aoc_common::{split_once, ParseError, Solution, Unsolved};
+use itertools::Itertools;
+
+pub struct Day23;
+
+type Network = HashMap<(char, char), HashSet<(char, char)>>;
+
+fn build_maximal_clique(
+    src: &(char, char),
+    adj: &HashMap<(char, char), HashSet<(char, char)>>,
+) -> HashSet<(char, char)> {
+    let mut clique = HashSet::new();
+    clique.insert(*src);
+    for dst in adj.get(src).unwrap().iter().sorted() {
+        if clique
+            .iter()
+            .all(|member| adj.get(member).unwrap().contains(dst))
+        {
+            clique.insert(*dst);
+        }
+    }
+    clique
+}
+
+impl Solution for Day23 {
+    type Input = Network;
+    type Answer1 = Unsolved;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (src, dst) = split_once(input, line, "-")?;
+                let computer = |name: &str| {
+                    name.chars()
+                        .collect_tuple()
+                        .ok_or_else(|| ParseError::at(input, name, "a two letter computer name"))
+                };
+                Ok((computer(src)?, computer(dst)?))
+            })
+            .try_fold(Network::new(), |mut acc, connection| {
+                let (src, dst) = connection?;
+                acc.entry(src).or_default().insert(dst);
+                acc.entry(dst).or_default().insert(src);
+                Ok(acc)
+            })
+    }
+
+    fn part1(_adj: &Network) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(adj: &Network) -> String {
+        let max_clique = adj.keys().fold(HashSet::new(), |acc, src| {
+            let curr_max_clique = build_maximal_clique(src, adj);
+            if curr_max_clique.len() >= acc.len() {
+                curr_max_clique
+            } else {
+                acc
+            }
+        });
+        let mut max_clique = max_clique.iter().collect_vec();
+        max_clique.sort();
+        max_clique
+            .iter()
+            .map(|(src, dst)| format!("{src}{dst}"))
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn part2_example() {
+        let input = Day23::parse(EXAMPLE).unwrap();
+        assert_eq!(Day23::part2(&input), "co,de,ka,ta");
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day23, "input.txt", "answers.txt");
+    }
+}
@@ -0,0 +1,372 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_common::circuit::{Netlist, NetlistGate};
+use aoc_common::{split_once, ParseError, Solution, Unsolved};
+
+pub struct Day24;
+
+#[derive(Debug)]
+pub struct Circuit {
+    inputs: HashMap<String, u32>,
+    topological_order: Vec<String>,
+    operations: HashMap<String, Operation>,
+}
+
+#[derive(Debug)]
+enum Operation {
+    And(String, String, String),
+    Or(String, String, String),
+    Xor(String, String, String),
+}
+
+impl Operation {
+    fn parse(input: &str, line: &str) -> Result<Self, ParseError> {
+        let (lhs, rest) = split_once(input, line, " ")?;
+        let (op, rest) = split_once(input, rest, " ")?;
+        let (rhs, target) = split_once(input, rest, " -> ")?;
+        match op {
+            "AND" => Ok(Operation::And(
+                lhs.to_string(),
+                rhs.to_string(),
+                target.to_string(),
+            )),
+            "OR" => Ok(Operation::Or(
+                lhs.to_string(),
+                rhs.to_string(),
+                target.to_string(),
+            )),
+            "XOR" => Ok(Operation::Xor(
+                lhs.to_string(),
+                rhs.to_string(),
+                target.to_string(),
+            )),
+            _ => Err(ParseError::at(input, op, "AND, OR or XOR")),
+        }
+    }
+
+    fn wires(&self) -> (&String, &String, &String) {
+        match self {
+            Operation::And(lhs, rhs, target)
+            | Operation::Or(lhs, rhs, target)
+            | Operation::Xor(lhs, rhs, target) => (lhs, rhs, target),
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Operation::And(..) => "AND",
+            Operation::Or(..) => "OR",
+            Operation::Xor(..) => "XOR",
+        }
+    }
+
+    fn colour(&self) -> &'static str {
+        match self {
+            Operation::And(..) => "lightblue",
+            Operation::Or(..) => "palegreen",
+            Operation::Xor(..) => "lightsalmon",
+        }
+    }
+
+    fn eval_and_update(&self, results: &mut HashMap<String, u32>) {
+        match self {
+            Operation::And(lhs, rhs, target) => {
+                let res = *results.get(lhs).unwrap() == 1 && *results.get(rhs).unwrap() == 1;
+                results.insert(target.clone(), res as u32);
+            }
+            Operation::Or(lhs, rhs, target) => {
+                let res = *results.get(lhs).unwrap() == 1 || *results.get(rhs).unwrap() == 1;
+                results.insert(target.clone(), res as u32);
+            }
+            Operation::Xor(lhs, rhs, target) => {
+                let res = *results.get(lhs).unwrap() != *results.get(rhs).unwrap();
+                results.insert(target.clone(), res as u32);
+            }
+        };
+    }
+}
+
+use itertools::Itertools;
+
+fn topological_sort(operations: &Vec<Operation>) -> Vec<String> {
+    let mut res = Vec::new();
+
+    let mut indegree = HashMap::new();
+    let mut adj = HashMap::new();
+    for operation in operations {
+        let (lhs, rhs, target) = operation.wires();
+        indegree.entry(lhs).or_insert(0);
+        indegree.entry(rhs).or_insert(0);
+        *indegree.entry(target).or_insert(0) += 2;
+        adj.entry(lhs).or_insert(Vec::new()).push(target);
+        adj.entry(rhs).or_insert(Vec::new()).push(target);
+        if !adj.contains_key(target) {
+            adj.insert(target, Vec::new());
+        }
+    }
+    let mut dq = indegree
+        .iter()
+        .fold(VecDeque::new(), |mut acc, (wire, count)| {
+            if *count == 0 {
+                acc.push_back(*wire);
+            }
+            acc
+        });
+
+    while !dq.is_empty() {
+        for _ in 0..dq.len() {
+            let wire = dq.pop_front().unwrap();
+            res.push(wire.clone());
+            for reachable in adj.get(wire).unwrap() {
+                *indegree.entry(reachable).or_default() -= 1;
+                if indegree[reachable] == 0 {
+                    dq.push_back(reachable);
+                }
+            }
+        }
+    }
+
+    res
+}
+
+/// Operands and target of a gate line, as slices of the input.
+fn gate_wires<'a>(input: &str, line: &'a str) -> Result<(&'a str, &'a str, &'a str), ParseError> {
+    let (lhs, rest) = split_once(input, line, " ")?;
+    let (_, rest) = split_once(input, rest, " ")?;
+    let (rhs, target) = split_once(input, rest, " -> ")?;
+    Ok((lhs, rhs, target))
+}
+
+/// Checks that every wire has exactly one driver and that the gates form no cycle, errors point
+/// at the first gate that breaks this.
+fn check_wiring(
+    input: &str,
+    inputs: &HashMap<String, u32>,
+    gate_lines: &[&str],
+    topological_order: &[String],
+) -> Result<(), ParseError> {
+    let gates = gate_lines
+        .iter()
+        .map(|line| gate_wires(input, line))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut driven: HashSet<&str> = inputs.keys().map(String::as_str).collect();
+    for (_, _, target) in &gates {
+        if !driven.insert(target) {
+            return Err(ParseError::at(
+                input,
+                target,
+                "a wire without another driver",
+            ));
+        }
+    }
+    for (lhs, rhs, _) in &gates {
+        if let Some(wire) = [lhs, rhs].into_iter().find(|wire| !driven.contains(*wire)) {
+            return Err(ParseError::at(
+                input,
+                wire,
+                "an input or a wire driven by a gate",
+            ));
+        }
+    }
+
+    // Wires on a cycle never lose all their incoming edges, so they are missing in the order.
+    let sorted: HashSet<&str> = topological_order.iter().map(String::as_str).collect();
+    match gates.iter().find(|(_, _, target)| !sorted.contains(target)) {
+        Some((_, _, target)) => Err(ParseError::at(input, target, "a gate outside of a cycle")),
+        None => Ok(()),
+    }
+}
+
+fn into_map(operations: Vec<Operation>) -> HashMap<String, Operation> {
+    operations
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, operation| {
+            match operation {
+                Operation::And(lhs, rhs, target) => {
+                    acc.insert(target.clone(), Operation::And(lhs, rhs, target))
+                }
+                Operation::Or(lhs, rhs, target) => {
+                    acc.insert(target.clone(), Operation::Or(lhs, rhs, target))
+                }
+                Operation::Xor(lhs, rhs, target) => {
+                    acc.insert(target.clone(), Operation::Xor(lhs, rhs, target))
+                }
+            };
+            acc
+        })
+}
+
+fn evaluate(
+    top_order: &Vec<String>,
+    operations: &HashMap<String, Operation>,
+    results: &mut HashMap<String, u32>,
+) {
+    for wire in top_order {
+        if results.contains_key(wire) {
+            continue;
+        }
+        let operation = operations.get(wire).unwrap();
+        operation.eval_and_update(results);
+    }
+}
+
+impl Circuit {
+    /// The circuit for exporting it, with the gates sorted by the wire they drive.
+    pub fn netlist(&self) -> Netlist<'_> {
+        Netlist {
+            inputs: self
+                .inputs
+                .iter()
+                .map(|(wire, &value)| (wire.as_str(), value == 1))
+                .collect(),
+            gates: self
+                .operations
+                .values()
+                .map(|operation| {
+                    let (lhs, rhs, target) = operation.wires();
+                    NetlistGate {
+                        lhs,
+                        operation: operation.name(),
+                        rhs,
+                        output: target,
+                        colour: operation.colour(),
+                    }
+                })
+                .sorted_by_key(|gate| gate.output)
+                .collect(),
+        }
+    }
+}
+
+impl Solution for Day24 {
+    type Input = Circuit;
+    type Answer1 = u64;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Circuit, ParseError> {
+        let lines = input.lines().collect_vec();
+
+        let inputs = lines.iter().take_while(|line| !line.is_empty()).try_fold(
+            HashMap::new(),
+            |mut acc, line| {
+                let (wire, value) = split_once(input, line, ": ")?;
+                match value {
+                    "0" | "1" => acc.insert(wire.to_string(), value.parse::<u32>().unwrap()),
+                    _ => return Err(ParseError::at(input, value, "'0' or '1'")),
+                };
+                Ok(acc)
+            },
+        )?;
+
+        let gate_lines = lines
+            .iter()
+            .skip_while(|line| !line.contains("->"))
+            .copied()
+            .collect_vec();
+        let operations = gate_lines
+            .iter()
+            .map(|line| Operation::parse(input, line))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let topological_order = topological_sort(&operations);
+        check_wiring(input, &inputs, &gate_lines, &topological_order)?;
+        let operations = into_map(operations);
+
+        Ok(Circuit {
+            inputs,
+            topological_order,
+            operations,
+        })
+    }
+
+    fn part1(circuit: &Circuit) -> u64 {
+        let mut eval = circuit.inputs.clone();
+        evaluate(&circuit.topological_order, &circuit.operations, &mut eval);
+
+        eval.iter()
+            .filter(|(wire, _)| wire.starts_with('z'))
+            .sorted()
+            .rev()
+            .fold(0, |acc, (_, &value)| acc << 1 | value as u64)
+    }
+
+    fn part2(_circuit: &Circuit) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day24::parse(EXAMPLE).unwrap();
+        assert_eq!(Day24::part1(&input), 4);
+    }
+
+    #[test]
+    fn wiring_errors_point_at_the_gate() {
+        let error = |from, to| {
+            Day24::parse(&EXAMPLE.replace(from, to))
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("XOR y01", "XOR w01"),
+            "<input>:9:9: expected an input or a wire driven by a gate, found \"w01\""
+        );
+        assert_eq!(
+            error("-> z02", "-> z01"),
+            "<input>:10:15: expected a wire without another driver, found \"z01\""
+        );
+        let err = Day24::parse("x00: 1\n\nx00 AND b -> a\nx00 OR a -> b\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "<input>:3:14: expected a gate outside of a cycle, found \"a\""
+        );
+    }
+
+    #[test]
+    fn netlist_is_normalized() {
+        let input =
+            Day24::parse("y00: 0\nx00: 1\n\ny00 XOR x00 -> z00\nx00 AND y00 -> c00\n").unwrap();
+        assert_eq!(
+            input.netlist().to_text(),
+            "x00: 1\ny00: 0\n\nx00 AND y00 -> c00\nx00 XOR y00 -> z00\n"
+        );
+        let netlist = Day24::parse(EXAMPLE).unwrap().netlist().to_text();
+        assert_eq!(Day24::parse(&netlist).unwrap().netlist().to_text(), netlist);
+    }
+
+    #[test]
+    fn dot_ranks_wires_by_bit() {
+        let dot = Day24::parse(EXAMPLE).unwrap().netlist().to_dot();
+        assert!(dot.contains("{ rank=source; edge [style=invis]; \"x00\" -> \"y00\" -> \"x01\" -> \"y01\" -> \"x02\" -> \"y02\"; }"));
+        assert!(dot.contains("{ rank=sink; edge [style=invis]; \"z00\" -> \"z01\" -> \"z02\"; }"));
+        assert!(dot.contains("g1 [label=\"XOR\", shape=box, style=filled, fillcolor=lightsalmon];"));
+        assert!(dot.contains("\"x01\" -> g1; \"y01\" -> g1; g1 -> \"z01\";"));
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day24, "input.txt", "answers.txt");
+    }
+}
@@ -0,0 +1,80 @@
+use aoc_common::{ParseError, Solution};
+use regex::Regex;
+
+use itertools::Itertools;
+
+pub struct Day3;
+
+fn sum_products(input: &str, respect_switches: bool) -> u32 {
+    let re = Regex::new(r"(do\(\))()|(don't\(\))()|mul\(([0-9]{1,3}),([0-9]{1,3})\)").unwrap();
+    re.captures_iter(input)
+        .fold((0, true), |(acc, enabled), cap| {
+            let (_, [fst, snd]) = cap.extract();
+            match fst {
+                "do()" => (acc, true),
+                "don't()" => (acc, false),
+                _ => {
+                    if enabled || !respect_switches {
+                        (
+                            acc + fst.parse::<u32>().unwrap() * snd.parse::<u32>().unwrap(),
+                            enabled,
+                        )
+                    } else {
+                        (acc, enabled)
+                    }
+                }
+            }
+        })
+        .0
+}
+
+impl Solution for Day3 {
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.lines().join(""))
+    }
+
+    fn part1(input: &String) -> u32 {
+        sum_products(input, false)
+    }
+
+    fn part2(input: &String) -> u32 {
+        sum_products(input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+    const EXAMPLE2: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day3::parse(EXAMPLE1).unwrap();
+        assert_eq!(Day3::part1(&input), 161);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day3::parse(EXAMPLE2).unwrap();
+        assert_eq!(Day3::part2(&input), 48);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day3, "input.txt", "answers.txt");
+    }
+}
@@ -0,0 +1,213 @@
+use aoc_common::{parse_grid, ParseError, Solution};
+
+pub struct Day4;
+
+fn north(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if i < 3 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i - k][j] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn south(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if i > grid.len() - 4 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i + k][j] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn east(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if j > grid[0].len() - 4 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i][j + k] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn west(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if j < 3 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i][j - k] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn northeast(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if i < 3 || j > grid[0].len() - 4 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i - k][j + k] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn northwest(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if i < 3 || j < 3 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i - k][j - k] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn southeast(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if i > grid.len() - 4 || j > grid[0].len() - 4 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i + k][j + k] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn southwest(i: usize, j: usize, grid: &[Vec<char>]) -> bool {
+    if i > grid.len() - 4 || j < 3 {
+        return false;
+    }
+    let lookup = ['X', 'M', 'A', 'S'];
+    for k in 1..4 {
+        if grid[i + k][j - k] != lookup[k] {
+            return false;
+        }
+    }
+    true
+}
+
+fn check(i: usize, j: usize, grid: &[Vec<char>]) -> u32 {
+    if i < 1 || j < 1 || i > grid.len() - 2 || j > grid[0].len() - 2 {
+        return 0;
+    }
+
+    // NE, SW, NW, SE
+    match (
+        grid[i - 1][j + 1],
+        grid[i + 1][j - 1],
+        grid[i - 1][j - 1],
+        grid[i + 1][j + 1],
+    ) {
+        ('M', 'S', 'M', 'S') => 1,
+        ('M', 'S', 'S', 'M') => 1,
+        ('S', 'M', 'M', 'S') => 1,
+        ('S', 'M', 'S', 'M') => 1,
+        _ => 0,
+    }
+}
+
+fn count_xmas(i: usize, j: usize, grid: &[Vec<char>]) -> u32 {
+    [
+        north, south, east, west, northeast, northwest, southeast, southwest,
+    ]
+    .iter()
+    .filter(|direction| direction(i, j, grid))
+    .count() as u32
+}
+
+fn count_cells(
+    grid: &[Vec<char>],
+    center: char,
+    f: impl Fn(usize, usize, &[Vec<char>]) -> u32,
+) -> u32 {
+    let mut res = 0;
+
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell != center {
+                continue;
+            }
+            res += f(i, j, grid);
+        }
+    }
+
+    res
+}
+
+impl Solution for Day4 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse_grid(input, "a letter", |ch| {
+            ch.is_ascii_uppercase().then_some(ch)
+        })
+    }
+
+    fn part1(grid: &Vec<Vec<char>>) -> u32 {
+        count_cells(grid, 'X', count_xmas)
+    }
+
+    fn part2(grid: &Vec<Vec<char>>) -> u32 {
+        count_cells(grid, 'A', check)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part1(&input), 18);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day4::parse(EXAMPLE).unwrap();
+        assert_eq!(Day4::part2(&input), 9);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day4, "input.txt", "answers.txt");
+    }
+}
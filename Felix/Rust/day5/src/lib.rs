@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse_number, split_once, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day5;
+
+pub struct Manual {
+    edges: Vec<Vec<u32>>,
+    seqs: Vec<Vec<u32>>,
+}
+
+fn build_adj(seq: &[u32], edges: &[Vec<u32>]) -> HashMap<u32, Vec<u32>> {
+    edges
+        .iter()
+        .fold(HashMap::<u32, Vec<u32>>::new(), |mut acc, edge| {
+            if seq.contains(&edge[0]) && seq.contains(&edge[1]) {
+                acc.entry(edge[1]).or_default().push(edge[0]);
+                acc.entry(edge[0]).or_insert_with(Vec::new);
+                acc
+            } else {
+                acc
+            }
+        })
+}
+
+fn is_top_ordered(seq: &[u32], adj: &HashMap<u32, Vec<u32>>) -> bool {
+    seq.iter().tuples().fold(true, |acc, (lhs, rhs)| {
+        acc && 1 + adj.get(lhs).unwrap().len() == adj.get(rhs).unwrap().len()
+    })
+}
+
+fn ordered_middle(seq: &[u32], edges: &[Vec<u32>]) -> u32 {
+    if is_top_ordered(seq, &build_adj(seq, edges)) {
+        seq[seq.len() / 2]
+    } else {
+        0
+    }
+}
+
+fn fix_top_order(seq: &[u32], edges: &[Vec<u32>]) -> u32 {
+    let adj = build_adj(seq, edges);
+    let top_ordered = is_top_ordered(seq, &adj);
+    if !top_ordered {
+        let mut fixed = adj.iter().map(|(k, v)| (*k, v.len() as u32)).collect_vec();
+        fixed.sort_by_key(|lhs| lhs.1);
+        fixed[fixed.len() / 2].0
+    } else {
+        0
+    }
+}
+
+impl Solution for Day5 {
+    type Input = Manual;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Manual, ParseError> {
+        let edges = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                split_once(input, line, "|").and_then(|(src, dst)| {
+                    Ok(vec![parse_number(input, src)?, parse_number(input, dst)?])
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let seqs = input
+            .lines()
+            .skip_while(|line| line.is_empty() || line.contains('|'))
+            .map(|line| {
+                line.split(",")
+                    .map(|ch| parse_number(input, ch))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Manual { edges, seqs })
+    }
+
+    fn part1(manual: &Manual) -> u32 {
+        manual
+            .seqs
+            .iter()
+            .fold(0, |acc, seq| acc + ordered_middle(seq, &manual.edges))
+    }
+
+    fn part2(manual: &Manual) -> u32 {
+        manual
+            .seqs
+            .iter()
+            .fold(0, |acc, seq| acc + fix_top_order(seq, &manual.edges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part1(&input), 143);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+        assert_eq!(Day5::part2(&input), 123);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day5, "input.txt", "answers.txt");
+    }
+}
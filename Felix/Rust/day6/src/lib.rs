@@ -0,0 +1,198 @@
+use std::collections::HashSet;
+
+use aoc_common::{parse_grid, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day6;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Path {
+    Up(usize, usize),
+    Down(usize, usize),
+    Left(usize, usize),
+    Right(usize, usize),
+    Cycle,
+    OutOfBounds,
+}
+
+impl Path {
+    fn hash(&self) -> (usize, usize, usize) {
+        match self {
+            Path::Up(i, j) => (0, *i, *j),
+            Path::Down(i, j) => (1, *i, *j),
+            Path::Left(i, j) => (2, *i, *j),
+            Path::Right(i, j) => (3, *i, *j),
+            Path::Cycle => panic!("cycle in hash"),
+            Path::OutOfBounds => panic!("oob in hash"),
+        }
+    }
+}
+
+fn make_move(p: &Path, grid: &[Vec<char>], visited: &mut HashSet<(usize, usize, usize)>) -> Path {
+    match *p {
+        Path::Up(mut i, j) => {
+            while i > 0 && grid[i - 1][j] != '#' {
+                i -= 1;
+                if visited.contains(&(0, i, j)) {
+                    return Path::Cycle;
+                }
+                visited.insert((0, i, j));
+            }
+            if i == 0 {
+                Path::OutOfBounds
+            } else {
+                Path::Right(i, j)
+            }
+        }
+        Path::Down(mut i, j) => {
+            while i < grid.len() - 1 && grid[i + 1][j] != '#' {
+                i += 1;
+                if visited.contains(&(1, i, j)) {
+                    return Path::Cycle;
+                }
+                visited.insert((1, i, j));
+            }
+            if i == grid.len() - 1 {
+                Path::OutOfBounds
+            } else {
+                Path::Left(i, j)
+            }
+        }
+        Path::Left(i, mut j) => {
+            while j > 0 && grid[i][j - 1] != '#' {
+                j -= 1;
+                if visited.contains(&(2, i, j)) {
+                    return Path::Cycle;
+                }
+                visited.insert((2, i, j));
+            }
+            if j == 0 {
+                Path::OutOfBounds
+            } else {
+                Path::Up(i, j)
+            }
+        }
+        Path::Right(i, mut j) => {
+            while j < grid[0].len() - 1 && grid[i][j + 1] != '#' {
+                j += 1;
+                if visited.contains(&(3, i, j)) {
+                    return Path::Cycle;
+                }
+                visited.insert((3, i, j));
+            }
+            if j == grid[0].len() - 1 {
+                Path::OutOfBounds
+            } else {
+                Path::Down(i, j)
+            }
+        }
+        Path::Cycle => panic!("cycle"),
+        Path::OutOfBounds => panic!("oob"),
+    }
+}
+
+fn find_start(grid: &[Vec<char>]) -> Path {
+    let mut start = Path::Up(0, 0);
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell == '^' {
+                start = Path::Up(i, j);
+            }
+        }
+    }
+    start
+}
+
+impl Solution for Day6 {
+    type Input = Vec<Vec<char>>;
+    type Answer1 = usize;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        parse_grid(input, "'.', '#' or '^'", |ch| {
+            matches!(ch, '.' | '#' | '^').then_some(ch)
+        })
+    }
+
+    fn part1(grid: &Vec<Vec<char>>) -> usize {
+        let start = find_start(grid);
+        let mut visited = HashSet::new();
+        let mut pos = start.clone();
+        visited.insert(pos.hash());
+
+        while pos != Path::OutOfBounds {
+            pos = make_move(&pos, grid, &mut visited);
+        }
+
+        visited.iter().map(|(_, i, j)| (i, j)).unique().count()
+    }
+
+    fn part2(grid: &Vec<Vec<char>>) -> u32 {
+        // Part two:
+        // How do we detect a loop?
+        // For each field on the original path, check if an obstacle at this position would lead us
+        // back to an already visited position.
+        // We explore into that turned direction until we hit a visited path of same direction or an
+        // obstacle.
+        let start = find_start(grid);
+        let mut grid = grid.clone();
+
+        let mut res = 0;
+        for i in 0..grid.len() {
+            for j in 0..grid[0].len() {
+                let mut new_visited = HashSet::new();
+                let prev = grid[i][j];
+                grid[i][j] = '#';
+                let mut pos = start.clone();
+                new_visited.insert(pos.hash());
+                while pos != Path::OutOfBounds && pos != Path::Cycle {
+                    pos = make_move(&pos, &grid, &mut new_visited);
+                }
+                if pos == Path::Cycle {
+                    res += 1;
+                }
+                grid[i][j] = prev;
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part1(&input), 41);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day6::parse(EXAMPLE).unwrap();
+        assert_eq!(Day6::part2(&input), 6);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day6, "input.txt", "answers.txt");
+    }
+}
@@ -0,0 +1,97 @@
+use aoc_common::{parse_number, split_once, ParseError, Solution};
+
+pub struct Day7;
+
+#[derive(Debug)]
+pub struct Computation(u64, Vec<u64>);
+
+fn concatenate(lhs: u64, rhs: u64) -> u64 {
+    let mut cc = lhs.to_string();
+    cc.push_str(&rhs.to_string());
+    cc.parse::<u64>().unwrap()
+}
+
+fn dfs(seq: &Vec<u64>, target: u64, i: usize, total: u64, with_concat: bool) -> bool {
+    if i == seq.len() {
+        return target == total;
+    }
+    dfs(seq, target, i + 1, total * seq[i], with_concat)
+        || dfs(seq, target, i + 1, total + seq[i], with_concat)
+        || with_concat && dfs(seq, target, i + 1, concatenate(total, seq[i]), with_concat)
+}
+
+fn calibration_result(input: &Vec<Computation>, with_concat: bool) -> u64 {
+    let mut res = 0;
+    for Computation(target, seq) in input {
+        if dfs(seq, *target, 0, 0, with_concat) {
+            res += target;
+        }
+    }
+    res
+}
+
+impl Solution for Day7 {
+    type Input = Vec<Computation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Computation>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (first, second) = split_once(input, line, ": ")?;
+                let first = parse_number(input, first)?;
+                let second = second
+                    .split(" ")
+                    .map(|e| parse_number(input, e))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Computation(first, second))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Vec<Computation>) -> u64 {
+        calibration_result(input, false)
+    }
+
+    fn part2(input: &Vec<Computation>) -> u64 {
+        calibration_result(input, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part1(&input), 3749);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day7::parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::part2(&input), 11387);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day7, "input.txt", "answers.txt");
+    }
+}
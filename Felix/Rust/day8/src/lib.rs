@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use aoc_common::{parse_grid, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day8;
+
+pub struct Map {
+    antennas: HashMap<char, Vec<(usize, usize)>>,
+    m: usize,
+    n: usize,
+}
+
+fn in_bounds(pos: (isize, isize), m: usize, n: usize) -> bool {
+    pos.0 >= 0 && pos.1 >= 0 && (pos.0 as usize) < m && (pos.1 as usize) < n
+}
+
+fn calculate_antinodes(
+    lhs: &(usize, usize),
+    rhs: &(usize, usize),
+    m: usize,
+    n: usize,
+    harmonics: bool,
+) -> HashSet<(usize, usize)> {
+    // . . o <- lhs
+    // . . .
+    // o . . <- rhs
+    // rhs = (2, 0), lhs = (0, 2)
+    // first = (-2, 4), second = (4, -2)
+    // slope = (2, -2)
+    // first = lhs - slope, second = rhs + slope
+    let mut antinodes = HashSet::new();
+    let mut lhs = (lhs.0 as isize, lhs.1 as isize);
+    let mut rhs = (rhs.0 as isize, rhs.1 as isize);
+    let slope = (rhs.0 - lhs.0, rhs.1 - lhs.1);
+    if !harmonics {
+        for antinode in [
+            (rhs.0 + slope.0, rhs.1 + slope.1),
+            (lhs.0 - slope.0, lhs.1 - slope.1),
+        ] {
+            if in_bounds(antinode, m, n) {
+                antinodes.insert((antinode.0 as usize, antinode.1 as usize));
+            }
+        }
+        return antinodes;
+    }
+    while in_bounds(rhs, m, n) {
+        antinodes.insert((rhs.0 as usize, rhs.1 as usize));
+        rhs = (rhs.0 + slope.0, rhs.1 + slope.1);
+    }
+    while in_bounds(lhs, m, n) {
+        antinodes.insert((lhs.0 as usize, lhs.1 as usize));
+        lhs = (lhs.0 - slope.0, lhs.1 - slope.1);
+    }
+    antinodes
+}
+
+fn count_antinodes(map: &Map, harmonics: bool) -> usize {
+    map.antennas
+        .values()
+        .fold(HashSet::<(usize, usize)>::new(), |acc, ant_list| {
+            ant_list.iter().combinations(2).fold(acc, |mut a, pair| {
+                a.extend(&calculate_antinodes(
+                    pair[0], pair[1], map.m, map.n, harmonics,
+                ));
+                a
+            })
+        })
+        .len()
+}
+
+impl Solution for Day8 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        let grid = parse_grid(input, "'.' or an antenna", |ch| {
+            (ch.is_ascii_alphanumeric() || ch == '.').then_some(ch)
+        })?;
+        let (m, n) = (grid.len(), grid[0].len());
+
+        let antennas = grid.into_iter().enumerate().fold(
+            HashMap::<char, Vec<(usize, usize)>>::new(),
+            |acc, (i, row)| {
+                row.into_iter().enumerate().fold(acc, |mut a, (j, cell)| {
+                    if cell != '.' {
+                        a.entry(cell).or_default().push((i, j));
+                    }
+                    a
+                })
+            },
+        );
+
+        Ok(Map { antennas, m, n })
+    }
+
+    fn part1(map: &Map) -> usize {
+        count_antinodes(map, false)
+    }
+
+    fn part2(map: &Map) -> usize {
+        count_antinodes(map, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part1(&input), 14);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day8::parse(EXAMPLE).unwrap();
+        assert_eq!(Day8::part2(&input), 34);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day8, "input.txt", "answers.txt");
+    }
+}
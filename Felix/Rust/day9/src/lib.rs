@@ -0,0 +1,131 @@
+use aoc_common::{parse_chars, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day9;
+
+fn compress1(layout: &mut [i32]) {
+    let (mut l, mut r) = (0, layout.len() - 1);
+    while l <= r - 2 {
+        while layout[l] != -1 {
+            l += 1;
+        }
+        layout.swap(l, r);
+        r -= 1;
+    }
+}
+
+fn compress2(pages: &[(usize, usize)], freespace: &mut [(usize, usize)]) -> usize {
+    let mut res = 0;
+    let calc = |lb, ub| (ub - lb + 1) * (lb + ub) / 2;
+    for (id, (start, size)) in pages.iter().enumerate().rev() {
+        if let Some((fstart, fsize)) = freespace
+            .iter_mut()
+            .take_while(|(fstart, _)| fstart <= start)
+            .find(|(_, fsize)| fsize >= size)
+        {
+            res += id * calc(*fstart, *fstart + size - 1);
+            *fstart += size;
+            *fsize -= size;
+        } else {
+            res += id * calc(*start, start + size - 1);
+        }
+    }
+    res
+}
+
+fn expand(layout: &[(usize, usize)]) -> Vec<i32> {
+    layout
+        .iter()
+        .enumerate()
+        .fold(Vec::new(), |mut acc, (id, (size, space))| {
+            acc.extend(vec![id as i32; *size]);
+            acc.extend(vec![-1; *space]);
+            acc
+        })
+}
+
+fn checksum(layout: &[i32]) -> usize {
+    layout
+        .iter()
+        .enumerate()
+        .filter(|(_, id)| **id != -1)
+        .fold(0, |acc, (i, id)| acc + i * *id as usize)
+}
+
+impl Solution for Day9 {
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+        let mut buf = parse_chars(input, input.trim_end(), "a digit", |ch| {
+            ch.to_digit(10).map(|digit| digit as usize)
+        })?;
+        buf.push(0);
+
+        Ok(buf.into_iter().tuples().collect_vec())
+    }
+
+    fn part1(layout: &Vec<(usize, usize)>) -> usize {
+        let mut expanded = expand(layout);
+        compress1(&mut expanded);
+        checksum(&expanded)
+    }
+
+    fn part2(layout: &Vec<(usize, usize)>) -> usize {
+        let mut freespace = layout
+            .iter()
+            .fold(
+                (Vec::<(usize, usize)>::new(), 0),
+                |(mut acc, prev), (size, space)| {
+                    if *space > 0 {
+                        acc.push((prev + size, *space));
+                    }
+                    (acc, prev + size + space)
+                },
+            )
+            .0;
+
+        let pages = layout
+            .iter()
+            .fold(
+                (Vec::<(usize, usize)>::new(), 0),
+                |(mut acc, prev), (size, space)| {
+                    acc.push((prev, *size));
+                    (acc, prev + size + space)
+                },
+            )
+            .0;
+
+        compress2(&pages, &mut freespace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2333133121414131402
+";
+
+    #[test]
+    fn part1_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part1(&input), 1928);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day9::parse(EXAMPLE).unwrap();
+        assert_eq!(Day9::part2(&input), 2858);
+    }
+
+    /// Puzzle inputs are not committed, run with `cargo test -- --ignored` after placing
+    /// `input.txt` next to the recorded `answers.txt` (one answer per line).
+    #[test]
+    #[ignore]
+    fn real_input_matches_recorded_answers() {
+        aoc_common::check_recorded_answers(&Day9, "input.txt", "answers.txt");
+    }
+}
@@ -0,0 +1,84 @@
+use std::any::Any;
+
+use aoc_common::{DynSolution, ParseError, Solution};
+use common::Torus;
+
+pub const DAYS: &[(u8, &dyn DynSolution)] = &[
+    (3, &day3::Day3),
+    (4, &day4::Day4),
+    (5, &day5::Day5),
+    (6, &day6::Day6),
+    (7, &day7::Day7),
+    (8, &day8::Day8),
+    (9, &day9::Day9),
+    (10, &day10::Day10),
+    (11, &day11::Day11),
+    (12, &day12::Day12),
+    (13, &day13::Day13),
+    (14, &day14::Day14),
+    (15, &day15::Day15),
+    (16, &day16::Day16),
+    (17, &day17::Day17),
+    (18, &day18::Day18),
+    (19, &day19::Day19),
+    (20, &day20::Day20),
+    (22, &day22::Day22),
+    (23, &day23::Day23),
+    (24, &day24::Day24),
+];
+
+pub fn find_day(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter()
+        .find(|(n, _)| *n == day)
+        .map(|(_, solution)| *solution)
+}
+
+/// Parses an input and formats it as a Graphviz graph (`dot`) or as text for inspection.
+pub type Exporter = fn(input: &str, dot: bool) -> Result<String, ParseError>;
+
+fn export_circuit(input: &str, dot: bool) -> Result<String, ParseError> {
+    let circuit = day24::Day24::parse(input)?;
+    let netlist = circuit.netlist();
+    Ok(if dot {
+        netlist.to_dot()
+    } else {
+        netlist.to_text()
+    })
+}
+
+fn export_program(input: &str, dot: bool) -> Result<String, ParseError> {
+    let computer = day17::Day17::parse(input)?;
+    Ok(if dot {
+        computer.to_dot()
+    } else {
+        computer.disassemble()
+    })
+}
+
+/// The exporter of the days whose input can be exported (17 and 24).
+pub fn find_exporter(day: u8) -> Option<Exporter> {
+    match day {
+        17 => Some(export_program),
+        24 => Some(export_circuit),
+        _ => None,
+    }
+}
+
+/// Moves a parsed input into a room of another size, for the days whose room is not part of the
+/// input.
+pub type Resizer = fn(input: &mut dyn Any, room: Torus);
+
+fn resize_bathroom(input: &mut dyn Any, room: Torus) {
+    input
+        .downcast_mut::<day14::Robots>()
+        .expect("input was parsed by day 14")
+        .resize(room);
+}
+
+/// The resizer of the days that take place in a room of their own size (14).
+pub fn find_resizer(day: u8) -> Option<Resizer> {
+    match day {
+        14 => Some(resize_bathroom),
+        _ => None,
+    }
+}
@@ -0,0 +1,182 @@
+use std::fs;
+use std::process::ExitCode;
+
+use aoc_common::bench::{self, Benchmark};
+use aoc_common::{DynSolution, Inputs};
+use clap::Parser;
+use common::Torus;
+use runner::{find_day, find_exporter, find_resizer, DAYS};
+
+/// Runs Felix's Advent of Code 2024 solutions.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Day to run, every day is run if omitted.
+    day: Option<u8>,
+
+    /// Input file, "-" reads stdin. By default the input is looked up in the inputs directory.
+    #[arg(long, requires = "day", conflicts_with = "example")]
+    input: Option<String>,
+
+    /// Directory with the puzzle inputs, overrides the AOC_INPUTS environment variable.
+    #[arg(long, value_name = "DIR")]
+    inputs: Option<String>,
+
+    /// Use the named example, e.g. "day<N>/<NAME>.txt", instead of the real input.
+    #[arg(long, value_name = "NAME")]
+    example: Option<String>,
+
+    /// Time parsing and both parts instead of printing the answers.
+    #[arg(long)]
+    bench: bool,
+
+    /// Number of benchmark runs per day, the table shows the median.
+    #[arg(long, default_value_t = 10, requires = "bench", value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Also write the benchmark results as JSON to this file.
+    #[arg(long, requires = "bench")]
+    json: Option<String>,
+
+    /// Also write the benchmark results as CSV to this file.
+    #[arg(long, requires = "bench")]
+    csv: Option<String>,
+
+    /// Write the input of the day (17 or 24) to this file for inspection, as a Graphviz graph if
+    /// it ends in ".dot" and as text otherwise.
+    #[arg(long, value_name = "FILE", requires = "day", conflicts_with = "bench")]
+    export: Option<String>,
+
+    /// Size of the room of day 14, e.g. "11x7" for the example instead of the puzzle's 101x103.
+    #[arg(long, value_name = "WxH", value_parser = parse_room, conflicts_with_all = ["bench", "export"])]
+    room: Option<Torus>,
+}
+
+fn parse_room(room: &str) -> Result<Torus, String> {
+    let size = |size: &str| size.parse::<i32>().ok().filter(|&size| size > 0);
+    room.split_once('x')
+        .and_then(|(width, height)| Some(Torus::new(size(width)?, size(height)?)))
+        .ok_or_else(|| format!("expected a size like \"101x103\", found {room:?}"))
+}
+
+fn run_day(
+    day: u8,
+    solution: &dyn DynSolution,
+    inputs: &mut Inputs,
+    file: Option<&str>,
+    room: Option<Torus>,
+) -> Result<(), String> {
+    let (file, input) = inputs.load(day, file).map_err(|err| err.to_string())?;
+    let mut input = solution
+        .parse_dyn(input)
+        .map_err(|err| err.with_file(file).to_string())?;
+    if let (Some(resize), Some(room)) = (find_resizer(day), room) {
+        resize(input.as_mut(), room);
+    }
+    if let Some(answer) = solution.part1_dyn(input.as_ref()) {
+        println!("Part 1: {answer}");
+    }
+    if let Some(answer) = solution.part2_dyn(input.as_ref()) {
+        println!("Part 2: {answer}");
+    }
+    Ok(())
+}
+
+fn bench_day(
+    day: u8,
+    solution: &dyn DynSolution,
+    inputs: &mut Inputs,
+    file: Option<&str>,
+    runs: usize,
+) -> Result<Benchmark, String> {
+    let (file, input) = inputs.load(day, file).map_err(|err| err.to_string())?;
+    bench::bench(day, solution, input, runs).map_err(|err| err.with_file(file).to_string())
+}
+
+fn export(day: u8, inputs: &mut Inputs, file: Option<&str>, export: &str) -> Result<(), String> {
+    let exporter = find_exporter(day).ok_or_else(|| format!("day {day} has no export"))?;
+    let (file, input) = inputs.load(day, file).map_err(|err| err.to_string())?;
+    let exported =
+        exporter(input, export.ends_with(".dot")).map_err(|err| err.with_file(file).to_string())?;
+    write_report(export, &exported)
+}
+
+fn write_report(file: &str, report: &str) -> Result<(), String> {
+    fs::write(file, report).map_err(|err| format!("cannot write {file}: {err}"))
+}
+
+fn run_bench(cli: &Cli, inputs: &mut Inputs) -> Result<(), String> {
+    let runs = cli.runs as usize;
+    let mut benchmarks = Vec::new();
+    match cli.day {
+        Some(day) => {
+            let solution = find(day)?;
+            benchmarks.push(bench_day(
+                day,
+                solution,
+                inputs,
+                cli.input.as_deref(),
+                runs,
+            )?);
+        }
+        None => {
+            for &(day, solution) in DAYS {
+                match bench_day(day, solution, inputs, None, runs) {
+                    Ok(benchmark) => benchmarks.push(benchmark),
+                    Err(message) => eprintln!("skipped day {day}: {message}"),
+                }
+            }
+        }
+    }
+
+    print!("{}", bench::table(&benchmarks));
+    if let Some(file) = &cli.json {
+        write_report(file, &bench::json(&benchmarks))?;
+    }
+    if let Some(file) = &cli.csv {
+        write_report(file, &bench::csv(&benchmarks))?;
+    }
+    Ok(())
+}
+
+fn find(day: u8) -> Result<&'static dyn DynSolution, String> {
+    find_day(day).ok_or_else(|| {
+        let available: Vec<_> = DAYS.iter().map(|(n, _)| n.to_string()).collect();
+        format!(
+            "day {day} is not implemented, available days are {}",
+            available.join(", ")
+        )
+    })
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let mut inputs = Inputs::new(cli.inputs.as_deref(), ".").with_example(cli.example.as_deref());
+    if cli.bench {
+        return run_bench(&cli, &mut inputs);
+    }
+
+    let Some(day) = cli.day else {
+        for &(day, solution) in DAYS {
+            println!("Day {day}");
+            if let Err(message) = run_day(day, solution, &mut inputs, None, cli.room) {
+                println!("skipped: {message}");
+            }
+        }
+        return Ok(());
+    };
+
+    if let Some(file) = &cli.export {
+        return export(day, &mut inputs, cli.input.as_deref(), file);
+    }
+    let solution = find(day)?;
+    run_day(day, solution, &mut inputs, cli.input.as_deref(), cli.room)
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
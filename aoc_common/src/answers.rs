@@ -0,0 +1,29 @@
+use std::fs;
+
+use crate::DynSolution;
+
+/// Checks the answers for a real puzzle input against previously recorded ones.
+///
+/// The answers file holds one line per part. Puzzle inputs are personal and not committed, so a
+/// missing input or answers file skips the check instead of failing it.
+pub fn check_recorded_answers(solution: &dyn DynSolution, input_file: &str, answers_file: &str) {
+    let (Ok(input), Ok(answers)) = (
+        fs::read_to_string(input_file),
+        fs::read_to_string(answers_file),
+    ) else {
+        eprintln!("skipping, {input_file} or {answers_file} does not exist");
+        return;
+    };
+    let input = solution
+        .parse_dyn(&input)
+        .unwrap_or_else(|err| panic!("{}", err.with_file(input_file)));
+    let mut answers = answers.lines();
+    for (part, answer) in [
+        (1, solution.part1_dyn(input.as_ref())),
+        (2, solution.part2_dyn(input.as_ref())),
+    ] {
+        if let (Some(answer), Some(expected)) = (answer, answers.next()) {
+            assert_eq!(answer, expected, "part {part} of {input_file}");
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Timing of solutions: parsing and both parts are measured separately over several runs and
+//! reported as a table, JSON or CSV.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::{DynSolution, ParseError};
+
+/// Timings of one stage (parsing or a part) over all runs of a benchmark.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Option<Stats> {
+        samples.sort();
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+}
+
+/// Benchmark results of a single day. Unsolved parts are not timed and have no stats.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl Benchmark {
+    fn stages(&self) -> [(&'static str, Option<Stats>); 3] {
+        [
+            ("parse", Some(self.parse)),
+            ("part1", self.part1),
+            ("part2", self.part2),
+        ]
+    }
+
+    /// Sum of the median timings of all stages.
+    pub fn total(&self) -> Duration {
+        self.stages()
+            .iter()
+            .filter_map(|(_, stats)| stats.map(|stats| stats.median))
+            .sum()
+    }
+}
+
+/// Times parsing, part 1 and part 2 of `solution` separately, each `runs` times. Every run
+/// parses the input again, so that no part profits from work done by a previous run.
+pub fn bench(
+    day: u8,
+    solution: &dyn DynSolution,
+    input: &str,
+    runs: usize,
+) -> Result<Benchmark, ParseError> {
+    assert!(runs > 0, "a benchmark needs at least one run");
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = solution.parse_dyn(black_box(input))?;
+        parse.push(start.elapsed());
+        if solution.has_part1() {
+            let start = Instant::now();
+            black_box(solution.part1_dyn(parsed.as_ref()));
+            part1.push(start.elapsed());
+        }
+        if solution.has_part2() {
+            let start = Instant::now();
+            black_box(solution.part2_dyn(parsed.as_ref()));
+            part2.push(start.elapsed());
+        }
+    }
+    Ok(Benchmark {
+        day,
+        runs,
+        parse: Stats::new(parse).expect("at least one run"),
+        part1: Stats::new(part1),
+        part2: Stats::new(part2),
+    })
+}
+
+/// Renders the median timings as a table with one row per day and a total row.
+pub fn table(benchmarks: &[Benchmark]) -> String {
+    let median = |stats: Option<Stats>| {
+        stats.map_or("-".to_string(), |stats| format!("{:.1?}", stats.median))
+    };
+    let mut table = format!(
+        "{:>5} {:>10} {:>10} {:>10} {:>10}\n",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for benchmark in benchmarks {
+        table += &format!(
+            "{:>5} {:>10} {:>10} {:>10} {:>10.1?}\n",
+            benchmark.day,
+            median(Some(benchmark.parse)),
+            median(benchmark.part1),
+            median(benchmark.part2),
+            benchmark.total()
+        );
+    }
+    let total: Duration = benchmarks.iter().map(Benchmark::total).sum();
+    table += &format!("{:>5} {:>43.1?}\n", "All", total);
+    table
+}
+
+/// Renders all timings in nanoseconds as a JSON array with one object per day.
+pub fn json(benchmarks: &[Benchmark]) -> String {
+    let days: Vec<_> = benchmarks
+        .iter()
+        .map(|benchmark| {
+            let stages: Vec<_> = benchmark
+                .stages()
+                .iter()
+                .map(|(stage, stats)| match stats {
+                    Some(stats) => format!(
+                        "\"{stage}\": {{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                        stats.min.as_nanos(),
+                        stats.median.as_nanos(),
+                        stats.max.as_nanos()
+                    ),
+                    None => format!("\"{stage}\": null"),
+                })
+                .collect();
+            format!(
+                "  {{\"day\": {}, \"runs\": {}, {}}}",
+                benchmark.day,
+                benchmark.runs,
+                stages.join(", ")
+            )
+        })
+        .collect();
+    if days.is_empty() {
+        return "[]\n".to_string();
+    }
+    format!("[\n{}\n]\n", days.join(",\n"))
+}
+
+/// Renders all timings in nanoseconds as CSV with one row per day and stage. Unsolved parts are
+/// left out.
+pub fn csv(benchmarks: &[Benchmark]) -> String {
+    let mut csv = "day,stage,runs,min_ns,median_ns,max_ns\n".to_string();
+    for benchmark in benchmarks {
+        for (stage, stats) in benchmark.stages() {
+            if let Some(stats) = stats {
+                csv += &format!(
+                    "{},{},{},{},{},{}\n",
+                    benchmark.day,
+                    stage,
+                    benchmark.runs,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.max.as_nanos()
+                );
+            }
+        }
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(min: u64, median: u64, max: u64) -> Stats {
+        Stats {
+            min: Duration::from_nanos(min),
+            median: Duration::from_nanos(median),
+            max: Duration::from_nanos(max),
+        }
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let samples = [30, 10, 20].map(Duration::from_nanos).to_vec();
+        assert_eq!(Stats::new(samples), Some(stats(10, 20, 30)));
+        assert_eq!(Stats::new(Vec::new()), None);
+    }
+
+    #[test]
+    fn renders_reports() {
+        let benchmarks = [Benchmark {
+            day: 7,
+            runs: 3,
+            parse: stats(1, 2, 3),
+            part1: Some(stats(4, 5, 6)),
+            part2: None,
+        }];
+        assert_eq!(
+            csv(&benchmarks),
+            "day,stage,runs,min_ns,median_ns,max_ns\n7,parse,3,1,2,3\n7,part1,3,4,5,6\n"
+        );
+        assert_eq!(
+            json(&benchmarks),
+            "[\n  {\"day\": 7, \"runs\": 3, \
+             \"parse\": {\"min_ns\": 1, \"median_ns\": 2, \"max_ns\": 3}, \
+             \"part1\": {\"min_ns\": 4, \"median_ns\": 5, \"max_ns\": 6}, \
+             \"part2\": null}\n]\n"
+        );
+        assert!(table(&benchmarks).contains("    7      2.0ns      5.0ns          -      7.0ns"));
+    }
+}
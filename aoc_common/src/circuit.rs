@@ -0,0 +1,521 @@
+//! Boolean circuits of two-input gates, evaluated event by event. The dependency graph is built
+//! once and the gates are evaluated in topological order. After an input changes or two gate
+//! outputs are swapped, only the gates downstream of the change are evaluated again, and only as
+//! long as their outputs change.
+//!
+//! A [`Netlist`] describes a circuit by the names of its wires instead, for exporting it as a
+//! Graphviz graph or as normalized text.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Write};
+
+/// Operation of a gate, such as AND, OR or XOR.
+pub trait Gate: Copy {
+    fn apply(self, lhs: bool, rhs: bool) -> bool;
+}
+
+/// A wire of a [`Circuit`], wires are numbered in the order they are first mentioned.
+pub type Wire = usize;
+
+/// Error for a circuit that cannot be evaluated.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CircuitError {
+    /// Wires that are read by gates but neither inputs nor driven by a gate.
+    Undriven(Vec<String>),
+    /// A wire that is driven by more than one gate, or is an input and driven by a gate.
+    MultipleDrivers(String),
+    /// Wires that form a cycle, each one is driven by a gate that reads the one before.
+    Cycle(Vec<String>),
+}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CircuitError::Undriven(wires) => write!(f, "undriven wires {}", wires.join(", ")),
+            CircuitError::MultipleDrivers(wire) => write!(f, "wire {wire} has several drivers"),
+            CircuitError::Cycle(wires) => write!(f, "gates form a cycle {}", wires.join(" -> ")),
+        }
+    }
+}
+
+impl Error for CircuitError {}
+
+#[derive(Debug, Copy, Clone)]
+struct Connection<G> {
+    gate: G,
+    lhs: Wire,
+    rhs: Wire,
+    output: Wire,
+}
+
+/// A circuit with the current value of every wire. All inputs start out as `false`.
+#[derive(Debug, Clone)]
+pub struct Circuit<G> {
+    names: Vec<String>,
+    wires: HashMap<String, Wire>,
+    connections: Vec<Connection<G>>,
+    /// Connections reading each wire.
+    readers: Vec<Vec<usize>>,
+    /// Connection driving each wire, `None` for inputs.
+    drivers: Vec<Option<usize>>,
+    /// Connections in topological order.
+    order: Vec<usize>,
+    /// Position of each connection in `order`.
+    ranks: Vec<usize>,
+    values: Vec<bool>,
+}
+
+impl<G: Gate> Circuit<G> {
+    /// Builds the circuit of the `inputs` wires and the gates given as `(lhs, gate, rhs,
+    /// output)`, and evaluates it.
+    pub fn new<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+        gates: impl IntoIterator<Item = (&'a str, G, &'a str, &'a str)>,
+    ) -> Result<Circuit<G>, CircuitError> {
+        let mut circuit = Circuit {
+            names: Vec::new(),
+            wires: HashMap::new(),
+            connections: Vec::new(),
+            readers: Vec::new(),
+            drivers: Vec::new(),
+            order: Vec::new(),
+            ranks: Vec::new(),
+            values: Vec::new(),
+        };
+        let mut inputs: HashSet<Wire> = inputs
+            .into_iter()
+            .map(|name| circuit.wire_or_insert(name))
+            .collect();
+        for (lhs, gate, rhs, output) in gates {
+            let connection = Connection {
+                gate,
+                lhs: circuit.wire_or_insert(lhs),
+                rhs: circuit.wire_or_insert(rhs),
+                output: circuit.wire_or_insert(output),
+            };
+            if circuit.drivers[connection.output].is_some() || inputs.remove(&connection.output) {
+                return Err(CircuitError::MultipleDrivers(output.to_string()));
+            }
+            let index = circuit.connections.len();
+            circuit.drivers[connection.output] = Some(index);
+            circuit.readers[connection.lhs].push(index);
+            if connection.rhs != connection.lhs {
+                circuit.readers[connection.rhs].push(index);
+            }
+            circuit.connections.push(connection);
+        }
+        let undriven: Vec<_> = (0..circuit.names.len())
+            .filter(|wire| circuit.drivers[*wire].is_none() && !inputs.contains(wire))
+            .map(|wire| circuit.names[wire].clone())
+            .collect();
+        if !undriven.is_empty() {
+            return Err(CircuitError::Undriven(undriven));
+        }
+        circuit.sort()?;
+        for i in 0..circuit.order.len() {
+            circuit.evaluate(circuit.order[i]);
+        }
+        Ok(circuit)
+    }
+
+    fn wire_or_insert(&mut self, name: &str) -> Wire {
+        if let Some(&wire) = self.wires.get(name) {
+            return wire;
+        }
+        let wire = self.names.len();
+        self.names.push(name.to_string());
+        self.wires.insert(name.to_string(), wire);
+        self.readers.push(Vec::new());
+        self.drivers.push(None);
+        self.values.push(false);
+        wire
+    }
+
+    /// Orders the connections topologically (Kahn's algorithm), or finds a cycle if there is
+    /// none.
+    fn sort(&mut self) -> Result<(), CircuitError> {
+        let driven_inputs = |connection: &Connection<G>| {
+            [connection.lhs, connection.rhs]
+                .into_iter()
+                .filter(|&wire| self.drivers[wire].is_some())
+                .count()
+        };
+        let mut missing: Vec<_> = self.connections.iter().map(driven_inputs).collect();
+        let mut order: Vec<_> = (0..missing.len()).filter(|&i| missing[i] == 0).collect();
+        let mut next = 0;
+        while let Some(&connection) = order.get(next) {
+            next += 1;
+            let output = self.connections[connection].output;
+            for &reader in &self.readers[output] {
+                let Connection { lhs, rhs, .. } = self.connections[reader];
+                missing[reader] -= usize::from(lhs == output) + usize::from(rhs == output);
+                if missing[reader] == 0 {
+                    order.push(reader);
+                }
+            }
+        }
+        if order.len() < self.connections.len() {
+            return Err(CircuitError::Cycle(self.cycle(&missing)));
+        }
+        self.ranks.resize(self.connections.len(), 0);
+        for (rank, &connection) in order.iter().enumerate() {
+            self.ranks[connection] = rank;
+        }
+        self.order = order;
+        Ok(())
+    }
+
+    /// Names of the wires on a cycle of the connections that could not be sorted, which still
+    /// miss some of their inputs. Every such input is driven by another unsorted connection, so
+    /// following them backwards has to come back to a connection already seen.
+    fn cycle(&self, missing: &[usize]) -> Vec<String> {
+        let mut connection = (0..missing.len()).find(|&i| missing[i] > 0).unwrap();
+        let mut seen = Vec::new();
+        while !seen.contains(&connection) {
+            seen.push(connection);
+            let Connection { lhs, rhs, .. } = self.connections[connection];
+            connection = [lhs, rhs]
+                .into_iter()
+                .filter_map(|wire| self.drivers[wire])
+                .find(|&driver| missing[driver] > 0)
+                .unwrap();
+        }
+        let start = seen.iter().position(|&seen| seen == connection).unwrap();
+        let mut cycle = seen.split_off(start);
+        cycle.reverse();
+        // Starting at the first connection makes the cycle the same wherever the search began.
+        let first = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap();
+        cycle.rotate_left(first);
+        cycle
+            .into_iter()
+            .map(|connection| self.names[self.connections[connection].output].clone())
+            .collect()
+    }
+
+    /// Evaluates a connection, returns whether its output changed.
+    fn evaluate(&mut self, connection: usize) -> bool {
+        let Connection {
+            gate,
+            lhs,
+            rhs,
+            output,
+        } = self.connections[connection];
+        let value = gate.apply(self.values[lhs], self.values[rhs]);
+        std::mem::replace(&mut self.values[output], value) != value
+    }
+
+    /// Evaluates the `changed` connections and everything downstream of an output that changes,
+    /// in topological order.
+    fn propagate(&mut self, changed: impl IntoIterator<Item = usize>) {
+        let mut queue: BinaryHeap<_> = changed
+            .into_iter()
+            .map(|connection| Reverse((self.ranks[connection], connection)))
+            .collect();
+        let mut last = None;
+        while let Some(Reverse((_, connection))) = queue.pop() {
+            // A connection reading two changed wires is queued twice, but both pop in a row.
+            if last.replace(connection) == Some(connection) || !self.evaluate(connection) {
+                continue;
+            }
+            let output = self.connections[connection].output;
+            for &reader in &self.readers[output] {
+                queue.push(Reverse((self.ranks[reader], reader)));
+            }
+        }
+    }
+
+    pub fn wire(&self, name: &str) -> Option<Wire> {
+        self.wires.get(name).copied()
+    }
+
+    pub fn name(&self, wire: Wire) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn wires(&self) -> impl Iterator<Item = (Wire, &str)> {
+        self.names.iter().map(String::as_str).enumerate()
+    }
+
+    /// The gates as `(lhs, gate, rhs, output)`, in the order they were given.
+    pub fn gates(&self) -> impl Iterator<Item = (Wire, G, Wire, Wire)> + '_ {
+        self.connections.iter().map(|connection| {
+            (
+                connection.lhs,
+                connection.gate,
+                connection.rhs,
+                connection.output,
+            )
+        })
+    }
+
+    pub fn is_input(&self, wire: Wire) -> bool {
+        self.drivers[wire].is_none()
+    }
+
+    pub fn value(&self, wire: Wire) -> bool {
+        self.values[wire]
+    }
+
+    /// Sets an input wire and updates the gates that depend on it.
+    ///
+    /// Panics if `wire` is driven by a gate.
+    pub fn set(&mut self, wire: Wire, value: bool) {
+        assert!(self.is_input(wire), "only inputs can be set");
+        if std::mem::replace(&mut self.values[wire], value) != value {
+            let readers = self.readers[wire].clone();
+            self.propagate(readers);
+        }
+    }
+
+    /// Swaps the outputs of the gates that drive `a` and `b` and updates the gates that depend
+    /// on them. A swap that would make the gates form a cycle is not done.
+    ///
+    /// Panics if `a` or `b` is an input.
+    pub fn swap_outputs(&mut self, a: Wire, b: Wire) -> Result<(), CircuitError> {
+        let (Some(driver_a), Some(driver_b)) = (self.drivers[a], self.drivers[b]) else {
+            panic!("only gate outputs can be swapped");
+        };
+        let swap = |circuit: &mut Circuit<G>| {
+            for driver in [driver_a, driver_b] {
+                let output = &mut circuit.connections[driver].output;
+                *output = if *output == a { b } else { a };
+            }
+            circuit.drivers.swap(a, b);
+        };
+        swap(self);
+        if let Err(err) = self.sort() {
+            swap(self);
+            self.sort().expect("the circuit was sorted before the swap");
+            return Err(err);
+        }
+        self.propagate([driver_a, driver_b]);
+        Ok(())
+    }
+}
+
+/// A gate of a [`Netlist`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct NetlistGate<'a> {
+    pub lhs: &'a str,
+    /// Name of the operation, such as `AND`.
+    pub operation: &'a str,
+    pub rhs: &'a str,
+    pub output: &'a str,
+    /// Fill colour of the gate in the Graphviz graph.
+    pub colour: &'a str,
+}
+
+/// A circuit as it is written down: the initial values of its inputs and its gates. The `x` and
+/// `y` wires are its inputs and the `z` wires its outputs, numbered by bit.
+#[derive(Debug, Clone, Default)]
+pub struct Netlist<'a> {
+    pub inputs: Vec<(&'a str, bool)>,
+    pub gates: Vec<NetlistGate<'a>>,
+}
+
+impl<'a> Netlist<'a> {
+    /// Wires starting with `prefix`, ordered by their bit.
+    fn bit_wires(&self, prefix: char) -> Vec<&'a str> {
+        let mut wires: Vec<_> = self
+            .inputs
+            .iter()
+            .map(|&(wire, _)| wire)
+            .chain(self.gates.iter().map(|gate| gate.output))
+            .filter(|wire| wire.starts_with(prefix))
+            .collect();
+        wires.sort_unstable();
+        wires.dedup();
+        wires
+    }
+
+    /// The circuit as a Graphviz graph. Gates are boxes coloured by their operation between the
+    /// wires they read and drive, the `x` and `y` inputs are lined up at the top and the `z`
+    /// outputs at the bottom, both in the order of their bits.
+    pub fn to_dot(&self) -> String {
+        let chain = |wires: &[&str]| {
+            wires
+                .iter()
+                .map(|wire| format!("\"{wire}\""))
+                .collect::<Vec<_>>()
+                .join(" -> ")
+        };
+        let (x, y) = (self.bit_wires('x'), self.bit_wires('y'));
+        let inputs: Vec<_> = (0..x.len().max(y.len()))
+            .flat_map(|bit| x.get(bit).into_iter().chain(y.get(bit)).copied())
+            .collect();
+        let outputs = self.bit_wires('z');
+
+        let mut dot =
+            String::from("digraph circuit {\n    rankdir=TB;\n    node [shape=ellipse];\n");
+        // The invisible edges keep the wires of a rank in the order of their bits.
+        for (rank, wires) in [("source", inputs), ("sink", outputs)] {
+            if !wires.is_empty() {
+                let wires = chain(&wires);
+                writeln!(dot, "    {{ rank={rank}; edge [style=invis]; {wires}; }}").unwrap();
+            }
+        }
+        for (i, gate) in self.gates.iter().enumerate() {
+            writeln!(
+                dot,
+                "    g{i} [label=\"{}\", shape=box, style=filled, fillcolor={}];",
+                gate.operation, gate.colour
+            )
+            .unwrap();
+            writeln!(
+                dot,
+                "    \"{}\" -> g{i}; \"{}\" -> g{i}; g{i} -> \"{}\";",
+                gate.lhs, gate.rhs, gate.output
+            )
+            .unwrap();
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// The circuit in the format of the puzzle input, but normalized so that equal circuits give
+    /// equal text: initial values sorted by wire, the inputs of every gate sorted and the gates
+    /// sorted by their inputs.
+    pub fn to_text(&self) -> String {
+        let mut inputs = self.inputs.clone();
+        inputs.sort_unstable();
+        let mut gates: Vec<_> = self
+            .gates
+            .iter()
+            .map(|gate| {
+                let (lhs, rhs) = (gate.lhs.min(gate.rhs), gate.lhs.max(gate.rhs));
+                (lhs, rhs, gate.operation, gate.output)
+            })
+            .collect();
+        gates.sort_unstable();
+
+        let mut text = String::new();
+        for (wire, value) in inputs {
+            writeln!(text, "{wire}: {}", u8::from(value)).unwrap();
+        }
+        text.push('\n');
+        for (lhs, rhs, operation, output) in gates {
+            writeln!(text, "{lhs} {operation} {rhs} -> {output}").unwrap();
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Copy, Clone)]
+    enum Op {
+        And,
+        Xor,
+    }
+
+    impl Gate for Op {
+        fn apply(self, lhs: bool, rhs: bool) -> bool {
+            match self {
+                Op::And => lhs && rhs,
+                Op::Xor => lhs != rhs,
+            }
+        }
+    }
+
+    fn half_adder() -> Circuit<Op> {
+        Circuit::new(
+            ["a", "b"],
+            [("a", Op::Xor, "b", "sum"), ("b", Op::And, "a", "carry")],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn evaluates_incrementally() {
+        let mut circuit = half_adder();
+        let [a, b, sum, carry] = ["a", "b", "sum", "carry"].map(|name| circuit.wire(name).unwrap());
+        assert!(!circuit.value(sum) && !circuit.value(carry));
+        circuit.set(a, true);
+        assert!(circuit.value(sum) && !circuit.value(carry));
+        circuit.set(b, true);
+        assert!(!circuit.value(sum) && circuit.value(carry));
+        circuit.swap_outputs(sum, carry).unwrap();
+        assert!(circuit.value(sum) && !circuit.value(carry));
+        assert!(circuit.is_input(a) && !circuit.is_input(sum));
+        let outputs: Vec<_> = circuit.gates().map(|(_, _, _, output)| output).collect();
+        assert_eq!(outputs, [carry, sum]);
+    }
+
+    #[test]
+    fn rejects_cycles_and_undriven_wires() {
+        let err = Circuit::new(
+            ["a"],
+            [
+                ("a", Op::And, "r", "p"),
+                ("p", Op::Xor, "a", "q"),
+                ("q", Op::And, "a", "r"),
+                ("q", Op::And, "p", "out"),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "gates form a cycle p -> q -> r");
+        let err = Circuit::new(["a"], [("a", Op::And, "b", "c"), ("c", Op::Xor, "d", "e")]);
+        assert_eq!(
+            err.unwrap_err(),
+            CircuitError::Undriven(vec!["b".to_string(), "d".to_string()])
+        );
+        let err = Circuit::new(["a"], [("a", Op::And, "a", "a")]).unwrap_err();
+        assert_eq!(err, CircuitError::MultipleDrivers("a".to_string()));
+    }
+
+    #[test]
+    fn swaps_into_a_cycle_are_undone() {
+        let mut circuit = Circuit::new(
+            ["a"],
+            [
+                ("a", Op::And, "a", "b"),
+                ("b", Op::Xor, "a", "c"),
+                ("c", Op::Xor, "a", "d"),
+            ],
+        )
+        .unwrap();
+        let [a, b, c, d] = ["a", "b", "c", "d"].map(|name| circuit.wire(name).unwrap());
+        circuit.set(a, true);
+        let err = circuit.swap_outputs(b, d).unwrap_err();
+        assert_eq!(
+            err,
+            CircuitError::Cycle(vec!["c".to_string(), "b".to_string()])
+        );
+        let values = |circuit: &Circuit<Op>| [b, c, d].map(|wire| circuit.value(wire));
+        assert_eq!(values(&circuit), [true, false, true]);
+        circuit.set(a, false);
+        assert_eq!(values(&circuit), [false, false, false]);
+    }
+
+    #[test]
+    fn netlist_exports() {
+        let gate = |lhs, operation, rhs, output| NetlistGate {
+            lhs,
+            operation,
+            rhs,
+            output,
+            colour: "white",
+        };
+        let netlist = Netlist {
+            inputs: vec![("y00", false), ("x01", true), ("x00", true)],
+            gates: vec![
+                gate("y00", "XOR", "x00", "z00"),
+                gate("x00", "AND", "y00", "z01"),
+            ],
+        };
+        assert_eq!(
+            netlist.to_text(),
+            "x00: 1\nx01: 1\ny00: 0\n\nx00 AND y00 -> z01\nx00 XOR y00 -> z00\n"
+        );
+        let dot = netlist.to_dot();
+        assert!(dot
+            .contains("    { rank=source; edge [style=invis]; \"x00\" -> \"y00\" -> \"x01\"; }\n"));
+        assert!(dot.contains("    { rank=sink; edge [style=invis]; \"z00\" -> \"z01\"; }\n"));
+        assert!(dot.contains("    g0 [label=\"XOR\", shape=box, style=filled, fillcolor=white];\n"));
+        assert!(dot.contains("    \"y00\" -> g0; \"x00\" -> g0; g0 -> \"z00\";\n"));
+    }
+}
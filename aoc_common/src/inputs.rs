@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Environment variable naming the directory puzzle inputs are looked up in.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+/// File name that stands for standard input.
+pub const STDIN: &str = "-";
+
+/// Error for a puzzle input that cannot be found or read.
+#[derive(Debug)]
+pub enum InputError {
+    Missing {
+        day: u8,
+        example: Option<String>,
+        searched: Vec<PathBuf>,
+    },
+    Unreadable {
+        file: String,
+        error: io::Error,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing {
+                day,
+                example,
+                searched,
+            } => {
+                match example {
+                    Some(example) => write!(f, "no example {example:?} for day {day}")?,
+                    None => write!(f, "no input for day {day}")?,
+                }
+                let searched: Vec<_> = searched.iter().map(|path| display_path(path)).collect();
+                write!(f, ", searched {}", searched.join(", "))
+            }
+            InputError::Unreadable { file, error } => write!(f, "cannot read {file}: {error}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+/// Finds and loads puzzle inputs.
+///
+/// Inputs are looked up in a single directory: the one passed on the command line, otherwise
+/// the one in [`INPUTS_DIR_VAR`], otherwise a default chosen by the caller. Both the
+/// `day7_input.txt` and the `day7/input.txt` layout are recognized, named examples are found as
+/// `day7_<name>.txt` or `day7/<name>.txt`. Loaded files are cached, so an input that is needed
+/// several times is read only once, which also makes `-` for stdin usable more than once.
+#[derive(Debug)]
+pub struct Inputs {
+    dir: PathBuf,
+    example: Option<String>,
+    cache: HashMap<String, String>,
+}
+
+impl Inputs {
+    pub fn new(dir: Option<&str>, default_dir: &str) -> Inputs {
+        let dir = dir
+            .map(PathBuf::from)
+            .or_else(|| env::var_os(INPUTS_DIR_VAR).map(PathBuf::from))
+            .unwrap_or_else(|| PathBuf::from(default_dir));
+        Inputs {
+            dir,
+            example: None,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Selects the named example instead of the real input.
+    pub fn with_example(mut self, example: Option<&str>) -> Inputs {
+        self.example = example.map(str::to_string);
+        self
+    }
+
+    /// Files that are tried, in order, for the input of `day`.
+    pub fn candidates(&self, day: u8) -> Vec<PathBuf> {
+        let name = self.example.as_deref().unwrap_or("input");
+        [
+            format!("day{day}_{name}.txt"),
+            format!("day{day}/{name}.txt"),
+        ]
+        .iter()
+        .map(|file| self.dir.join(file))
+        .collect()
+    }
+
+    /// Path of the input of `day`, or an error listing every path that was searched.
+    pub fn find(&self, day: u8) -> Result<String, InputError> {
+        let candidates = self.candidates(day);
+        candidates
+            .iter()
+            .find(|path| path.is_file())
+            .map(|path| display_path(path))
+            .ok_or_else(|| InputError::Missing {
+                day,
+                example: self.example.clone(),
+                searched: candidates,
+            })
+    }
+
+    /// Loads the input of `day`, from `file` if given, otherwise from the discovered file.
+    /// Returns the name of the input for error messages together with its contents.
+    pub fn load(&mut self, day: u8, file: Option<&str>) -> Result<(String, &str), InputError> {
+        let file = match file {
+            Some(file) => file.to_string(),
+            None => self.find(day)?,
+        };
+        if !self.cache.contains_key(&file) {
+            let contents = read(&file).map_err(|error| InputError::Unreadable {
+                file: file.clone(),
+                error,
+            })?;
+            self.cache.insert(file.clone(), contents);
+        }
+        let name = if file == STDIN {
+            "<stdin>".to_string()
+        } else {
+            file.clone()
+        };
+        Ok((name, &self.cache[&file]))
+    }
+}
+
+fn display_path(path: &Path) -> String {
+    path.strip_prefix(".").unwrap_or(path).display().to_string()
+}
+
+fn read(file: &str) -> io::Result<String> {
+    if file == STDIN {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        Ok(contents)
+    } else {
+        fs::read_to_string(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_input_lists_searched_paths() {
+        let inputs = Inputs::new(Some("inputs"), ".").with_example(Some("small"));
+        let err = inputs.find(7).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "no example \"small\" for day 7, searched inputs/day7_small.txt, inputs/day7/small.txt"
+        );
+    }
+
+    #[test]
+    fn loads_discovered_input_once() {
+        let dir = env::temp_dir().join(format!("aoc_inputs_{}", std::process::id()));
+        fs::create_dir_all(dir.join("day3")).unwrap();
+        fs::write(dir.join("day3/input.txt"), "mul(2,4)").unwrap();
+
+        let mut inputs = Inputs::new(dir.to_str(), ".");
+        let (name, contents) = inputs.load(3, None).unwrap();
+        assert_eq!(name, dir.join("day3/input.txt").display().to_string());
+        assert_eq!(contents, "mul(2,4)");
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(inputs.load(3, Some(&name)).unwrap().1, "mul(2,4)");
+    }
+}
@@ -0,0 +1,14 @@
+mod answers;
+pub mod bench;
+pub mod circuit;
+mod inputs;
+mod parse;
+pub mod path;
+pub mod region;
+pub mod render;
+mod solution;
+
+pub use answers::check_recorded_answers;
+pub use inputs::{InputError, Inputs, INPUTS_DIR_VAR, STDIN};
+pub use parse::{parse_chars, parse_grid, parse_number, split_once, ParseError};
+pub use solution::{run, DynSolution, MaybeAnswer, Solution, Unsolved};
@@ -0,0 +1,169 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Error for malformed puzzle input, pointing at the offending token.
+///
+/// Lines and columns are 1-based, columns are counted in characters. The file name is not known
+/// while parsing and is attached afterwards by whoever read the input, see [`ParseError::with_file`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Creates an error for `token`, which has to be a slice of `input`. Line and column are
+    /// derived from the position of the slice, so no bookkeeping is needed while parsing.
+    /// An empty token reports the end of the line or the end of the input instead.
+    pub fn at(input: &str, token: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + token.len() <= input.len())
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rfind('\n')
+            .map_or(before, |newline| &before[newline + 1..])
+            .chars()
+            .count()
+            + 1;
+        let rest = &input[offset..];
+        let found = if !token.is_empty() {
+            format!("{token:?}")
+        } else if rest.trim_end().is_empty() {
+            "end of input".to_string()
+        } else if rest.starts_with(['\n', '\r']) {
+            "end of line".to_string()
+        } else {
+            "nothing".to_string()
+        };
+        ParseError::new(line, column, expected, found)
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: expected {}, found {}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column,
+            self.expected,
+            self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn parse_number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, "a number"))
+}
+
+/// Splits `s`, a slice of `input`, at the first occurrence of `separator`.
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(separator)
+        .ok_or_else(|| ParseError::at(input, s, format!("{separator:?}")))
+}
+
+/// Maps every character of `line`, a slice of `input`, using `mapper`. Characters for which the
+/// mapper returns `None` are reported as not being `expected`.
+pub fn parse_chars<T>(
+    input: &str,
+    line: &str,
+    expected: &str,
+    mapper: impl Fn(char) -> Option<T>,
+) -> Result<Vec<T>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            mapper(c).ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], expected))
+        })
+        .collect()
+}
+
+/// Parses a rectangular grid of characters into rows, see [`parse_chars`].
+pub fn parse_grid<T>(
+    input: &str,
+    expected: &str,
+    mapper: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let width = input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::at(input, "", "a grid"))?
+        .chars()
+        .count();
+    input
+        .lines()
+        .map(|line| {
+            if line.chars().count() != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("a row of {width} cells"),
+                ));
+            }
+            parse_chars(input, line, expected, &mapper)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_points_at_token() {
+        let input = "1 2\n3 x4\n";
+        let err = parse_number::<u32>(input, &input[6..8]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.found, "\"x4\"");
+    }
+
+    #[test]
+    fn error_at_end_of_input() {
+        let input = "#.#\n#.\n";
+        let err = parse_grid(input, "a cell", Some).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let err = split_once(input, &input[input.len()..], ",").unwrap_err();
+        assert_eq!(err.found, "end of input");
+        assert_eq!(
+            err.with_file("input.txt").to_string(),
+            "input.txt:3:1: expected \",\", found end of input"
+        );
+    }
+}
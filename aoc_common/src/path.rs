@@ -0,0 +1,268 @@
+//! Shortest paths over any graph given by a start state and a successor function, so that grid
+//! positions, positions with a facing or any other state can be searched the same way.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Cost of a path: anything that can be added up and compared, with `Default` as zero. Step
+/// costs must not be negative.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Result of a search from a start state: the distance of every reached state and all of its
+/// predecessors on a shortest path. The predecessors form a DAG that contains every shortest
+/// path from the start, not just one of them.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    start: S,
+    distances: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> ShortestPaths<S, C> {
+    fn new(start: S) -> ShortestPaths<S, C> {
+        ShortestPaths {
+            distances: HashMap::from([(start.clone(), C::default())]),
+            predecessors: HashMap::new(),
+            start,
+        }
+    }
+
+    /// Records that `state` can be reached from `from` at `distance`. Returns whether that is
+    /// shorter than any path known so far, i.e. whether the state has to be expanded (again).
+    fn relax(&mut self, from: &S, state: S, distance: C) -> bool {
+        match self.distances.get(&state) {
+            Some(&known) if known < distance => false,
+            Some(&known) if known == distance => {
+                if state != self.start {
+                    self.predecessors
+                        .entry(state)
+                        .or_default()
+                        .push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(state.clone(), distance);
+                self.predecessors.insert(state, vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// Length of the shortest path to `state`, `None` if it cannot be reached.
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    /// Distances of all reached states.
+    pub fn distances(&self) -> &HashMap<S, C> {
+        &self.distances
+    }
+
+    /// Every state that directly precedes `state` on some shortest path.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from the start to `target`, both included.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All states on any shortest path from the start to one of `targets`. Targets that were not
+    /// reached are ignored; to only follow the overall shortest paths, pass just the targets with
+    /// the smallest distance.
+    pub fn on_shortest_paths(&self, targets: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut to_visit: Vec<_> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+        while let Some(state) = to_visit.pop() {
+            if seen.insert(state.clone()) {
+                to_visit.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth-first search from `start`, every step counts one.
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> ShortestPaths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut to_visit = VecDeque::from([(start, 0)]);
+    while let Some((state, distance)) = to_visit.pop_front() {
+        for next in successors(&state) {
+            if paths.relax(&state, next.clone(), distance + 1) {
+                to_visit.push_back((next, distance + 1));
+            }
+        }
+    }
+    paths
+}
+
+/// Entry of the priority queue, the heap is a max-heap so the ordering is reversed.
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's algorithm from `start`. `successors` yields the neighbours of a state together with
+/// the cost of the step. The whole reachable graph is explored.
+pub fn dijkstra<S, C, I>(start: S, successors: impl FnMut(&S) -> I) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    search(start, successors, |_| C::default(), |_| false).0
+}
+
+/// A* search from `start` to the first state that `is_goal` accepts. `heuristic` must never
+/// overestimate the remaining cost. Returns a shortest path to the goal with its cost.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> C,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (paths, goal) = search(start, successors, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.path_to(&goal)?, paths.distance(&goal)?))
+}
+
+fn search<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    heuristic: impl Fn(&S) -> C,
+    is_goal: impl Fn(&S) -> bool,
+) -> (ShortestPaths<S, C>, Option<S>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = ShortestPaths::new(start.clone());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+    while let Some(Queued { cost, state, .. }) = queue.pop() {
+        if paths.distances[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            return (paths, Some(state));
+        }
+        for (next, step) in successors(&state) {
+            let cost = cost + step;
+            if paths.relax(&state, next.clone(), cost) {
+                queue.push(Queued {
+                    priority: cost + heuristic(&next),
+                    cost,
+                    state: next,
+                });
+            }
+        }
+    }
+    (paths, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a -1-> b -1-> d and a -2-> c -0-> d: two shortest paths of length 2, e is unreachable.
+    fn edges(state: &char) -> Vec<(char, u32)> {
+        match state {
+            'a' => vec![('b', 1), ('c', 2)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('d', 0)],
+            'e' => vec![('a', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_keeps_all_shortest_paths() {
+        let paths = dijkstra('a', edges);
+        assert_eq!(paths.distance(&'d'), Some(2));
+        assert_eq!(paths.distance(&'e'), None);
+        assert_eq!(paths.predecessors(&'d').len(), 2);
+        assert_eq!(paths.path_to(&'d').unwrap().len(), 3);
+        assert_eq!(paths.path_to(&'a'), Some(vec!['a']));
+        assert_eq!(
+            paths.on_shortest_paths(['d', 'e']),
+            HashSet::from(['a', 'b', 'c', 'd'])
+        );
+    }
+
+    #[test]
+    fn bfs_on_a_grid() {
+        let open = |(x, y): &(i32, i32)| (0..4).contains(x) && (0..3).contains(y) && *x != 1;
+        let paths = bfs((0, 0), |&(x, y)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(open)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(paths.distance(&(0, 2)), Some(2));
+        assert_eq!(paths.distance(&(2, 0)), None);
+        assert_eq!(paths.path_to(&(0, 2)), Some(vec![(0, 0), (0, 1), (0, 2)]));
+    }
+
+    #[test]
+    fn astar_finds_goal() {
+        let successors = |&n: &u32| [(n + 1, 1), (n * 2, 1)];
+        let (path, cost) = astar(1, successors, |_| 0, |&n| n == 10).unwrap();
+        assert_eq!(cost, 4);
+        assert_eq!(path.first(), Some(&1));
+        assert_eq!(path.last(), Some(&10));
+        assert_eq!(astar('e', edges, |_| 0, |&s| s == 'x'), None);
+    }
+}
@@ -0,0 +1,217 @@
+//! Connected regions of a grid and their geometry: area, perimeter, straight sides, corners and
+//! bounding box. Positions are `(x, y)` like in [`crate::render::Frame`], so that any grid
+//! representation can be labelled through closures over its positions.
+
+use std::collections::HashSet;
+
+type Pos = (usize, usize);
+
+/// Orthogonal neighbours of `(x, y)` that are not left of or above the grid.
+fn neighbours((x, y): Pos) -> impl Iterator<Item = Pos> {
+    [
+        x.checked_sub(1).map(|x| (x, y)),
+        y.checked_sub(1).map(|y| (x, y)),
+        Some((x + 1, y)),
+        Some((x, y + 1)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// A set of orthogonally connected cells.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Region {
+    cells: HashSet<Pos>,
+}
+
+impl Region {
+    pub fn contains(&self, position: Pos) -> bool {
+        self.cells.contains(&position)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.cells.iter().copied()
+    }
+
+    /// Number of cells.
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Number of cell edges between the region and anything else, holes included.
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|&cell| 4 - neighbours(cell).filter(|&n| self.contains(n)).count())
+            .sum()
+    }
+
+    /// Number of corners of the outline, holes included. Every cell corner is checked from the
+    /// cell's side: it is convex if both neighbours next to it are outside, and concave if both
+    /// are inside but the diagonal neighbour between them is not.
+    pub fn corners(&self) -> usize {
+        let inside = |(x, y): Pos, dx: isize, dy: isize| match (
+            x.checked_add_signed(dx),
+            y.checked_add_signed(dy),
+        ) {
+            (Some(x), Some(y)) => self.contains((x, y)),
+            _ => false,
+        };
+        self.cells
+            .iter()
+            .map(|&cell| {
+                [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+                    .into_iter()
+                    .filter(|&(dx, dy)| {
+                        let (horizontal, vertical) = (inside(cell, dx, 0), inside(cell, 0, dy));
+                        (!horizontal && !vertical)
+                            || (horizontal && vertical && !inside(cell, dx, dy))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of straight fence pieces around the region. A closed outline has as many sides
+    /// as corners.
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    /// Top left and bottom right cell of the smallest rectangle around the region, inclusive.
+    pub fn bounding_box(&self) -> (Pos, Pos) {
+        let xs = self.cells.iter().map(|&(x, _)| x);
+        let ys = self.cells.iter().map(|&(_, y)| y);
+        (
+            (xs.clone().min().unwrap(), ys.clone().min().unwrap()),
+            (xs.max().unwrap(), ys.max().unwrap()),
+        )
+    }
+}
+
+/// The regions of a `width` x `height` grid, with the region of every cell.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    width: usize,
+    labels: Vec<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Labels the cells for which `member` holds, neighbouring members are in the same region
+    /// if they are `connected`. Regions are numbered in the order of their first cell, row by
+    /// row.
+    pub fn new(
+        width: usize,
+        height: usize,
+        member: impl Fn(Pos) -> bool,
+        connected: impl Fn(Pos, Pos) -> bool,
+    ) -> Regions {
+        let mut labels = vec![None; width * height];
+        let mut regions = Vec::new();
+        for start in (0..height).flat_map(|y| (0..width).map(move |x| (x, y))) {
+            if labels[start.1 * width + start.0].is_some() || !member(start) {
+                continue;
+            }
+            let label = regions.len();
+            labels[start.1 * width + start.0] = Some(label);
+            let mut cells = HashSet::from([start]);
+            let mut todo = vec![start];
+            while let Some(cell) = todo.pop() {
+                for next @ (x, y) in neighbours(cell) {
+                    if x < width
+                        && y < height
+                        && labels[y * width + x].is_none()
+                        && member(next)
+                        && connected(cell, next)
+                    {
+                        labels[y * width + x] = Some(label);
+                        cells.insert(next);
+                        todo.push(next);
+                    }
+                }
+            }
+            regions.push(Region { cells });
+        }
+        Regions {
+            width,
+            labels,
+            regions,
+        }
+    }
+
+    /// Regions of neighbouring cells with equal values, every cell belongs to one.
+    pub fn of_equal<T: PartialEq>(
+        width: usize,
+        height: usize,
+        value: impl Fn(Pos) -> T,
+    ) -> Regions {
+        Regions::new(width, height, |_| true, |a, b| value(a) == value(b))
+    }
+
+    /// Regions of neighbouring cells for which `member` holds, other cells belong to none.
+    pub fn matching(width: usize, height: usize, member: impl Fn(Pos) -> bool) -> Regions {
+        Regions::new(width, height, member, |_, _| true)
+    }
+
+    /// Number of the region of the cell at `(x, y)`, an index into [`Regions::regions`].
+    pub fn label(&self, (x, y): Pos) -> Option<usize> {
+        if x >= self.width {
+            return None;
+        }
+        self.labels.get(y * self.width + x).copied().flatten()
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn regions(grid: &str) -> Regions {
+        let rows: Vec<&[u8]> = grid.lines().map(str::as_bytes).collect();
+        Regions::of_equal(rows[0].len(), rows.len(), |(x, y)| rows[y][x])
+    }
+
+    #[test]
+    fn labels_regions_of_equal_cells() {
+        let regions = regions("AAB\nBAB\nBBA\n");
+        assert_eq!(regions.regions().len(), 4);
+        assert_eq!(regions.label((0, 0)), regions.label((1, 1)));
+        assert_ne!(regions.label((2, 0)), regions.label((0, 1)));
+        assert_eq!(regions.label((0, 1)), regions.label((1, 2)));
+        assert_eq!(regions.label((3, 0)), None);
+        let areas: Vec<_> = regions.iter().map(Region::area).collect();
+        assert_eq!(areas, [3, 2, 3, 1]);
+    }
+
+    #[test]
+    fn geometry_with_a_hole() {
+        let regions = regions("AAAA\nABBA\nAAAA\n");
+        let ring = &regions.regions()[0];
+        assert_eq!(ring.area(), 10);
+        assert_eq!(ring.perimeter(), 14 + 6);
+        assert_eq!(ring.sides(), 8);
+        assert_eq!(ring.bounding_box(), ((0, 0), (3, 2)));
+        let hole = &regions.regions()[1];
+        assert_eq!((hole.perimeter(), hole.corners()), (6, 4));
+        assert_eq!(hole.bounding_box(), ((1, 1), (2, 1)));
+    }
+
+    #[test]
+    fn matching_cells_only() {
+        let rows = ["#.#", "#..", ".##"];
+        let regions = Regions::matching(3, 3, |(x, y)| rows[y].as_bytes()[x] == b'#');
+        assert_eq!(regions.regions().len(), 3);
+        assert_eq!(regions.label((1, 0)), None);
+        let concave = Regions::matching(2, 2, |(x, y)| (x, y) != (1, 1));
+        assert_eq!(concave.regions()[0].sides(), 6);
+    }
+}
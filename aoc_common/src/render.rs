@@ -0,0 +1,308 @@
+//! Rendering of grids for inspection: as plain text, as ANSI-coloured terminal output or as a
+//! PNG/PPM image. A grid is first turned into a [`Frame`] with one glyph and colour per cell,
+//! paths can be overlaid on top, then the frame is printed or drawn.
+
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::path::Path;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(64, 160, 43);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(223, 142, 29);
+
+    /// The `i`-th colour of a palette of distinct colours, for telling apart an unknown number
+    /// of things such as garden regions. Neighbouring indices get clearly different hues.
+    pub fn palette(i: usize) -> Rgb {
+        // Stepping the hue by the golden angle never repeats and spreads the colours evenly.
+        let hue = (i as f64 * 137.507_764) % 360.0;
+        let sector = hue / 60.0;
+        let rising = (sector.fract() * 180.0) as u8 + 60;
+        let falling = 240 - (sector.fract() * 180.0) as u8;
+        match sector as u8 {
+            0 => Rgb(240, rising, 60),
+            1 => Rgb(falling, 240, 60),
+            2 => Rgb(60, 240, rising),
+            3 => Rgb(60, falling, 240),
+            4 => Rgb(rising, 60, 240),
+            _ => Rgb(240, 60, falling),
+        }
+    }
+}
+
+/// A single cell of a frame: its glyph for text output and an optional colour. The colour is
+/// the glyph colour in the terminal and fills the cell in images.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Cell {
+    pub glyph: char,
+    pub colour: Option<Rgb>,
+}
+
+impl Cell {
+    pub fn new(glyph: char) -> Cell {
+        Cell {
+            glyph,
+            colour: None,
+        }
+    }
+
+    pub fn coloured(glyph: char, colour: Rgb) -> Cell {
+        Cell {
+            glyph,
+            colour: Some(colour),
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(glyph: char) -> Cell {
+        Cell::new(glyph)
+    }
+}
+
+/// A rendered grid, row by row. `Display` prints the plain glyphs.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    overlay: Vec<Option<Rgb>>,
+}
+
+impl Frame {
+    /// Renders a `width` x `height` grid, `cell(x, y)` gives the cell in column `x` of row `y`.
+    pub fn new<C: Into<Cell>>(
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut(usize, usize) -> C,
+    ) -> Frame {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| cell(x, y).into())
+            .collect();
+        Frame {
+            width,
+            height,
+            cells,
+            overlay: vec![None; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
+        (x < self.width && y < self.height).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// Highlights the cells at `positions`, given as `(x, y)`, in `colour`. It is drawn as the
+    /// background in the terminal and as a marker in the middle of the cell in images, so the
+    /// cell underneath stays visible. Positions outside of the frame are ignored, later
+    /// overlays win over earlier ones.
+    pub fn overlay<P: Into<(usize, usize)>>(
+        &mut self,
+        positions: impl IntoIterator<Item = P>,
+        colour: Rgb,
+    ) -> &mut Frame {
+        for (x, y) in positions.into_iter().map(Into::into) {
+            if x < self.width && y < self.height {
+                self.overlay[y * self.width + x] = Some(colour);
+            }
+        }
+        self
+    }
+
+    /// The frame with 24-bit ANSI colour escapes, for printing to a terminal.
+    pub fn ansi(&self) -> String {
+        let mut result = String::new();
+        for (row, overlay) in self
+            .cells
+            .chunks(self.width.max(1))
+            .zip(self.overlay.chunks(self.width.max(1)))
+        {
+            for (cell, overlay) in row.iter().zip(overlay) {
+                if cell.colour.is_none() && overlay.is_none() {
+                    result.push(cell.glyph);
+                    continue;
+                }
+                if let Some(Rgb(r, g, b)) = cell.colour {
+                    result += &format!("\x1b[38;2;{r};{g};{b}m");
+                }
+                if let Some(Rgb(r, g, b)) = overlay {
+                    result += &format!("\x1b[48;2;{r};{g};{b}m");
+                }
+                result.push(cell.glyph);
+                result += "\x1b[0m";
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    /// Draws every cell as a `cell_size` x `cell_size` square in its colour, black if it has
+    /// none.
+    pub fn image(&self, cell_size: usize) -> Image {
+        assert!(cell_size > 0, "cells must be at least one pixel");
+        let mut image = Image::new(self.width * cell_size, self.height * cell_size);
+        // The overlay marker leaves a border of a quarter cell, on tiny cells it covers it all.
+        let inset = cell_size / 4;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let cell = self.cells[y * self.width + x];
+                let colour = cell.colour.unwrap_or(Rgb::BLACK);
+                image.fill(x * cell_size, y * cell_size, cell_size, colour);
+                if let Some(overlay) = self.overlay[y * self.width + x] {
+                    image.fill(
+                        x * cell_size + inset,
+                        y * cell_size + inset,
+                        cell_size - 2 * inset,
+                        overlay,
+                    );
+                }
+            }
+        }
+        image
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            let row: String = row.iter().map(|cell| cell.glyph).collect();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An RGB image, row by row.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![Rgb::BLACK; width * height],
+        }
+    }
+
+    fn fill(&mut self, left: usize, top: usize, size: usize, colour: Rgb) {
+        for y in top..top + size {
+            self.pixels[y * self.width + left..y * self.width + left + size].fill(colour);
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect()
+    }
+
+    /// The image in the binary PPM format.
+    pub fn ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.bytes());
+        ppm
+    }
+
+    pub fn write_ppm(&self, file: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(file, self.ppm())
+    }
+
+    pub fn write_png(&self, file: impl AsRef<Path>) -> io::Result<()> {
+        let writer = BufWriter::new(File::create(file)?);
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.bytes())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Writes the image as PPM if `file` ends in `.ppm`, as PNG otherwise.
+    pub fn write(&self, file: impl AsRef<Path>) -> io::Result<()> {
+        let file = file.as_ref();
+        if file.extension().is_some_and(|extension| extension == "ppm") {
+            self.write_ppm(file)
+        } else {
+            self.write_png(file)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        Frame::new(2, 2, |x, y| match (x, y) {
+            (0, 0) => Cell::coloured('#', Rgb::RED),
+            _ => Cell::new('.'),
+        })
+    }
+
+    #[test]
+    fn renders_text_and_ansi() {
+        let mut frame = frame();
+        assert_eq!(frame.to_string(), "#.\n..\n");
+        frame.overlay([(1, 1), (5, 5)], Rgb::BLUE);
+        assert_eq!(
+            frame.ansi(),
+            "\x1b[38;2;220;50;47m#\x1b[0m.\n.\x1b[48;2;38;139;210m.\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn renders_images() {
+        let mut frame = frame();
+        frame.overlay([(1, 0)], Rgb::WHITE);
+        let image = frame.image(4);
+        assert_eq!((image.width, image.height), (8, 8));
+        assert_eq!(image.pixels[0], Rgb::RED);
+        assert_eq!(image.pixels[4], Rgb::BLACK);
+        assert_eq!(image.pixels[8 + 5], Rgb::WHITE);
+        assert!(image.ppm().starts_with(b"P6\n8 8\n255\n"));
+        assert_eq!(image.ppm().len(), 11 + 8 * 8 * 3);
+    }
+
+    #[test]
+    fn writes_png_and_ppm() {
+        let image = frame().image(3);
+        let dir = std::env::temp_dir();
+        let png = dir.join(format!("aoc_render_{}.png", std::process::id()));
+        let ppm = png.with_extension("ppm");
+        image.write(&png).unwrap();
+        image.write(&ppm).unwrap();
+        assert!(std::fs::read(&png).unwrap().starts_with(b"\x89PNG"));
+        assert_eq!(std::fs::read(&ppm).unwrap(), image.ppm());
+        std::fs::remove_file(png).unwrap();
+        std::fs::remove_file(ppm).unwrap();
+    }
+
+    #[test]
+    fn palette_colours_differ() {
+        let colours: std::collections::HashSet<_> = (0..50).map(Rgb::palette).collect();
+        assert_eq!(colours.len(), 50);
+    }
+}
@@ -0,0 +1,121 @@
+use std::any::{Any, TypeId};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::process::ExitCode;
+
+use crate::ParseError;
+
+/// A solution for a single day: the raw puzzle input is parsed once into a typed value from
+/// which both parts are answered.
+pub trait Solution {
+    type Input: 'static;
+    type Answer1: Display + 'static;
+    type Answer2: Display + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Answer type for parts that are not solved (yet).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// Answer of a part that does not exist for every input, for example on a small example that
+/// lacks what the puzzle relies on. It is printed as "no answer" then instead of panicking.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MaybeAnswer<T>(pub Option<T>);
+
+impl<T> From<Option<T>> for MaybeAnswer<T> {
+    fn from(answer: Option<T>) -> Self {
+        MaybeAnswer(answer)
+    }
+}
+
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => write!(f, "no answer"),
+        }
+    }
+}
+
+/// Object safe version of [`Solution`], so that solutions of different days can be stored in a
+/// single registry. Answers are rendered to strings, `None` marks an [`Unsolved`] part.
+pub trait DynSolution {
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part1_dyn(&self, input: &dyn Any) -> Option<String>;
+
+    fn part2_dyn(&self, input: &dyn Any) -> Option<String>;
+
+    fn has_part1(&self) -> bool;
+
+    fn has_part2(&self) -> bool;
+}
+
+fn is_solved<T: 'static>() -> bool {
+    TypeId::of::<T>() != TypeId::of::<Unsolved>()
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref()
+        .expect("input was parsed by a different solution")
+}
+
+impl<S: Solution> DynSolution for S {
+    fn parse_dyn(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part1_dyn(&self, input: &dyn Any) -> Option<String> {
+        self.has_part1()
+            .then(|| S::part1(downcast_input::<S>(input)).to_string())
+    }
+
+    fn part2_dyn(&self, input: &dyn Any) -> Option<String> {
+        self.has_part2()
+            .then(|| S::part2(downcast_input::<S>(input)).to_string())
+    }
+
+    fn has_part1(&self) -> bool {
+        is_solved::<S::Answer1>()
+    }
+
+    fn has_part2(&self) -> bool {
+        is_solved::<S::Answer2>()
+    }
+}
+
+/// Reads the puzzle input from `file`, solves both parts and prints the answers. An unreadable
+/// or malformed input file is reported on stderr instead.
+pub fn run<S: Solution>(file: &str) -> ExitCode {
+    let input = match fs::read_to_string(file) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: cannot read input file {file}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match S::parse(&input) {
+        Ok(input) => {
+            println!("Part 1: {}", S::part1(&input));
+            println!("Part 2: {}", S::part2(&input));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err.with_file(file));
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::{DynSolution, Inputs};
+use clap::Parser;
+
+/// Runs Andreas's and Felix's solutions of a day on the same input and compares their answers.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Day to compare.
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Input file both solutions are run on, "-" reads stdin. By default the input is looked up
+    /// in the inputs directory.
+    #[arg(conflicts_with = "example")]
+    input: Option<String>,
+
+    /// Directory with the puzzle inputs, overrides the AOC_INPUTS environment variable.
+    #[arg(long, value_name = "DIR")]
+    inputs: Option<String>,
+
+    /// Use the named example instead of the real input.
+    #[arg(long, value_name = "NAME")]
+    example: Option<String>,
+}
+
+/// Answer of a single part with its timing, `None` if the part is unsolved. A panic is reported
+/// as an error, it is a finding like any other and must not abort the comparison.
+type PartResult = Result<Option<(String, Duration)>, String>;
+
+/// Answers and timings of one author's solution.
+struct Run {
+    parse: Duration,
+    parts: [PartResult; 2],
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> Result<(T, Duration), String> {
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        format!("panicked: {message}")
+    })?;
+    Ok((result, start.elapsed()))
+}
+
+fn run_solution(solution: &dyn DynSolution, input: &str, file: &str) -> Result<Run, String> {
+    let (parsed, parse) = timed(|| solution.parse_dyn(input))?;
+    let parsed = parsed.map_err(|err| err.with_file(file).to_string())?;
+    let part = |answer: fn(&dyn DynSolution, &dyn Any) -> Option<String>| {
+        timed(|| answer(solution, parsed.as_ref()))
+            .map(|(answer, time)| answer.map(|answer| (answer, time)))
+    };
+    Ok(Run {
+        parse,
+        parts: [
+            part(|s, input| s.part1_dyn(input)),
+            part(|s, input| s.part2_dyn(input)),
+        ],
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Verdict {
+    Match,
+    Mismatch,
+    OnlyAndreas,
+    OnlyFelix,
+    Unsolved,
+    Failed,
+}
+
+impl Verdict {
+    fn of(andreas: Option<&str>, felix: Option<&str>) -> Verdict {
+        match (andreas, felix) {
+            (Some(a), Some(f)) if a == f => Verdict::Match,
+            (Some(_), Some(_)) => Verdict::Mismatch,
+            (Some(_), None) => Verdict::OnlyAndreas,
+            (None, Some(_)) => Verdict::OnlyFelix,
+            (None, None) => Verdict::Unsolved,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Verdict::Match => "match",
+            Verdict::Mismatch => "MISMATCH",
+            Verdict::OnlyAndreas => "only Andreas",
+            Verdict::OnlyFelix => "only Felix",
+            Verdict::Unsolved => "unsolved",
+            Verdict::Failed => "failed",
+        }
+    }
+}
+
+fn format_part(part: &PartResult) -> String {
+    match part {
+        Ok(Some((answer, time))) => format!("{answer} ({time:.1?})"),
+        Ok(None) => "unsolved".to_string(),
+        Err(message) => format!("error: {message}"),
+    }
+}
+
+fn format_run(run: &Result<Run, String>) -> [String; 3] {
+    match run {
+        Ok(run) => [
+            format!("{:.1?}", run.parse),
+            format_part(&run.parts[0]),
+            format_part(&run.parts[1]),
+        ],
+        Err(message) => [format!("error: {message}"), String::new(), String::new()],
+    }
+}
+
+fn column_width(header: &str, cells: &[String]) -> usize {
+    cells
+        .iter()
+        .map(|cell| cell.chars().count())
+        .chain([header.len()])
+        .max()
+        .unwrap_or(0)
+}
+
+/// Prints the comparison table and returns the verdict for both parts, `None` if one of the
+/// solutions could not parse the input and there is nothing to compare.
+fn report(andreas: &Result<Run, String>, felix: &Result<Run, String>) -> Option<[Verdict; 2]> {
+    let verdicts = match (andreas, felix) {
+        (Ok(andreas), Ok(felix)) => {
+            Some(
+                [0, 1].map(|part| match (&andreas.parts[part], &felix.parts[part]) {
+                    (Ok(a), Ok(f)) => Verdict::of(
+                        a.as_ref().map(|(answer, _)| answer.as_str()),
+                        f.as_ref().map(|(answer, _)| answer.as_str()),
+                    ),
+                    _ => Verdict::Failed,
+                }),
+            )
+        }
+        _ => None,
+    };
+
+    let andreas = format_run(andreas);
+    let felix = format_run(felix);
+    let width = column_width("Andreas", &andreas);
+    let felix_width = column_width("Felix", &felix);
+
+    println!("{:8}{:width$}  Felix", "", "Andreas");
+    for (row, label) in ["parse", "part 1", "part 2"].iter().enumerate() {
+        let verdict = match (row, verdicts) {
+            (1 | 2, Some(verdicts)) => verdicts[row - 1].describe(),
+            _ => "",
+        };
+        let line = format!(
+            "{label:8}{:width$}  {:felix_width$}  {verdict}",
+            andreas[row], felix[row]
+        );
+        println!("{}", line.trim_end());
+    }
+    verdicts
+}
+
+fn run(cli: &Cli) -> Result<bool, String> {
+    let andreas = andreas::registry::find_day(cli.day).map(|day| day.solution);
+    let felix = felix::find_day(cli.day);
+    let (andreas, felix) = match (andreas, felix) {
+        (Some(andreas), Some(felix)) => (andreas, felix),
+        (None, None) => return Err(format!("nobody solved day {}", cli.day)),
+        (None, _) => return Err(format!("Andreas did not solve day {}", cli.day)),
+        (_, None) => return Err(format!("Felix did not solve day {}", cli.day)),
+    };
+
+    let mut inputs = Inputs::new(cli.inputs.as_deref(), ".").with_example(cli.example.as_deref());
+    let (file, input) = inputs
+        .load(cli.day, cli.input.as_deref())
+        .map_err(|err| err.to_string())?;
+    let andreas = run_solution(andreas, input, &file);
+    let felix = run_solution(felix, input, &file);
+
+    println!("Day {} ({})", cli.day, file);
+    let verdicts = report(&andreas, &felix);
+    Ok(verdicts.is_some_and(|verdicts| {
+        !verdicts.contains(&Verdict::Mismatch) && !verdicts.contains(&Verdict::Failed)
+    }))
+}
+
+fn main() -> ExitCode {
+    match run(&Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdicts() {
+        assert_eq!(Verdict::of(Some("42"), Some("42")), Verdict::Match);
+        assert_eq!(Verdict::of(Some("42"), Some("43")), Verdict::Mismatch);
+        assert_eq!(Verdict::of(Some("42"), None), Verdict::OnlyAndreas);
+        assert_eq!(Verdict::of(None, Some("42")), Verdict::OnlyFelix);
+        assert_eq!(Verdict::of(None, None), Verdict::Unsolved);
+    }
+}